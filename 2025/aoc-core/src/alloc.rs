@@ -0,0 +1,149 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
+
+// Process-wide, so measurements are only meaningful while one thing runs at a time.
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// Heap usage of one measured step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Calls to `alloc`, `alloc_zeroed` and `realloc`.
+    pub allocations: u64,
+    /// Bytes requested by those calls.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live when the step started.
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "{} B", self.0)
+        } else {
+            write!(f, "{value:.2} {}", UNITS[unit])
+        }
+    }
+}
+
+/// Runs `f`, counting its heap usage when the `count-allocations` feature
+/// installs the counting allocator; `None` otherwise.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "count-allocations") {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = ALLOCATED.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: ALLOCATED.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (value, Some(stats))
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::*;
+
+    /// The system allocator, counting every allocation.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocated(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size as u64, Relaxed);
+    }
+
+    // SAFETY: every call is forwarded unchanged to `System`; the counters
+    // are only updated alongside it.
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_uses_binary_units() {
+        let stats = AllocStats {
+            allocations: 3,
+            bytes: 1536,
+            peak: 512,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocations, 1.50 KiB allocated, 512 B peak"
+        );
+        assert_eq!(Bytes(5 * 1024 * 1024).to_string(), "5.00 MiB");
+    }
+}
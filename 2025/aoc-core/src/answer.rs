@@ -0,0 +1,72 @@
+use std::fmt;
+
+/// The result of running one part of a puzzle, normalised so that days
+/// returning `i32`, `i64` or `u64` can be compared and printed the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Solved(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Self::Solved(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{answer}"),
+            Self::Unsolved => write!(f, "{Unsolved}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Solved(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(i32, i64, u32, u64, u128, usize, String, &str);
+
+/// Placeholder answer type for a part that has not been implemented yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl From<Unsolved> for Answer {
+    fn from(_: Unsolved) -> Self {
+        Self::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers_convert_to_solved_answers() {
+        assert_eq!(Answer::from(-3i32), Answer::Solved("-3".to_string()));
+        assert_eq!(Answer::from(733u64).to_string(), "733");
+    }
+
+    #[test]
+    fn test_unsolved_converts_to_unsolved_answer() {
+        let answer = Answer::from(Unsolved);
+        assert!(!answer.is_solved());
+        assert_eq!(answer.to_string(), "not implemented");
+    }
+}
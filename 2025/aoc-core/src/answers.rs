@@ -0,0 +1,252 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use toml_edit::{DocumentMut, Item, Table, value};
+
+use crate::{Answer, Part};
+
+/// Accepted answers keyed by year, day and part, stored as TOML:
+///
+/// ```toml
+/// [2025.day05]
+/// part1 = "733"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, AnswersError> {
+        toml::from_str(contents).map_err(|err| AnswersError::Format(err.to_string()))
+    }
+
+    /// Sets the answer and writes just that key to the file at `path`, keeping
+    /// its comments and layout.
+    pub fn record(
+        &mut self,
+        path: &Path,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<(), AnswersError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(AnswersError::Io(err)),
+        };
+        let updated = record_in(&contents, year, day, part, answer)?;
+        fs::write(path, updated).map_err(AnswersError::Io)?;
+        self.set(year, day, part, answer);
+        Ok(())
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.0
+            .get(&year.to_string())?
+            .get(&day_key(day))?
+            .get(&part_key(part))
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: impl Into<String>) {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), answer.into());
+    }
+
+    pub fn verdict(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match (self.get(year, day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Answer::Solved(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Every `(year, day)` that has at least one recorded answer, in order.
+    pub fn days(&self) -> Vec<(u16, u8)> {
+        let mut days: Vec<(u16, u8)> = self
+            .0
+            .iter()
+            .filter_map(|(year, days)| Some((year.parse().ok()?, days)))
+            .flat_map(|(year, days)| {
+                days.keys()
+                    .filter_map(move |day| Some((year, day.strip_prefix("day")?.parse().ok()?)))
+            })
+            .collect();
+        days.sort();
+        days
+    }
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// `contents` with `answer` recorded, editing the document rather than re-serialising it.
+fn record_in(
+    contents: &str,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<String, AnswersError> {
+    let mut document: DocumentMut = contents
+        .parse()
+        .map_err(|err: toml_edit::TomlError| AnswersError::Format(err.to_string()))?;
+    let not_a_table = |key: String| AnswersError::Format(format!("`{key}` is not a table"));
+    // The year table stays implicit so days keep their `[2025.dayNN]` headers.
+    let year_table = document
+        .entry(&year.to_string())
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or_else(|| not_a_table(year.to_string()))?;
+    let day_table = year_table
+        .entry(&day_key(day))
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| not_a_table(format!("{year}.{}", day_key(day))))?;
+    day_table[&part_key(part)] = value(answer);
+    Ok(document.to_string())
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{part}")
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to access answers file: {err}"),
+            Self::Format(err) => write!(f, "invalid answers file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+[2025.day05]
+part1 = "733"
+part2 = "345821388687084"
+
+[2025.day01]
+part2 = "6768"
+"#;
+
+    #[test]
+    fn test_get_recorded_answer() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(answers.get(2025, 5, Part::One), Some("733"));
+        assert_eq!(answers.get(2025, 1, Part::One), None);
+        assert_eq!(answers.get(2024, 5, Part::One), None);
+    }
+
+    #[test]
+    fn test_verdict_compares_against_recorded_answer() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(
+            answers.verdict(2025, 5, Part::One, &Answer::from(733u64)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verdict(2025, 5, Part::One, &Answer::from(734u64)),
+            Verdict::Fail {
+                expected: "733".to_string()
+            }
+        );
+        assert_eq!(
+            answers.verdict(2025, 1, Part::Two, &Answer::Unsolved),
+            Verdict::Fail {
+                expected: "6768".to_string()
+            }
+        );
+        assert_eq!(
+            answers.verdict(2025, 1, Part::One, &Answer::from(1u64)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_days_are_sorted() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(answers.days(), vec![(2025, 1), (2025, 5)]);
+    }
+
+    #[test]
+    fn test_set_round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.set(2025, 7, Part::Two, "5137133207830");
+        let reparsed = Answers::parse(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(reparsed.get(2025, 7, Part::Two), Some("5137133207830"));
+    }
+
+    #[test]
+    fn test_record_keeps_comments_and_layout() {
+        let contents = "# Accepted answers.\n\n[2025.day05]\npart1 = \"733\" # first try\n";
+        assert_eq!(
+            record_in(contents, 2025, 5, Part::Two, "14").unwrap(),
+            "# Accepted answers.\n\n[2025.day05]\npart1 = \"733\" # first try\npart2 = \"14\"\n"
+        );
+        assert_eq!(
+            record_in(contents, 2025, 6, Part::One, "1").unwrap(),
+            format!("{contents}\n[2025.day06]\npart1 = \"1\"\n")
+        );
+        assert_eq!(
+            record_in("", 2025, 1, Part::Two, "6768").unwrap(),
+            "[2025.day01]\npart2 = \"6768\"\n"
+        );
+    }
+
+    #[test]
+    fn test_record_updates_the_file_and_the_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        let mut answers = Answers::default();
+        answers
+            .record(&path, 2025, 7, Part::Two, "5137133207830")
+            .unwrap();
+        assert_eq!(answers.get(2025, 7, Part::Two), Some("5137133207830"));
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_rejects_malformed_file() {
+        assert!(Answers::parse("[2025.day05]\npart1 = ").is_err());
+    }
+}
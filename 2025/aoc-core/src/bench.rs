@@ -0,0 +1,243 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{ParseError, Part, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 10,
+            iterations: 100,
+        }
+    }
+}
+
+/// Summary of the timed iterations of one step; durations serialize as nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "as_nanos")]
+    pub p95: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarise!");
+        samples.sort();
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Self {
+            iterations: samples.len(),
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Runs `f` `options.warmup` times untimed, then times `options.iterations` runs.
+pub fn measure<T>(options: BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartStats {
+    pub part: Part,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub warmup: usize,
+    pub parse: Stats,
+    pub parts: Vec<PartStats>,
+}
+
+/// Times parsing and each requested part separately. Parts that are not
+/// implemented yet are skipped without being run.
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    options: BenchOptions,
+) -> Result<Benchmark, ParseError> {
+    let parsed = puzzle.parse(input)?;
+    let parse = measure(options, || puzzle.parse(input));
+    let parts = parts
+        .iter()
+        .filter(|&&part| puzzle.is_implemented(part))
+        .map(|&part| PartStats {
+            part,
+            stats: measure(options, || puzzle.solve(parsed.as_ref(), part)),
+        })
+        .collect();
+    Ok(Benchmark {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        title: puzzle.title(),
+        warmup: options.warmup,
+        parse,
+        parts,
+    })
+}
+
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {}: {} (Advent of Code {})",
+            self.day, self.title, self.year
+        )?;
+        writeln!(
+            f,
+            "{} iterations after {} warm-up runs",
+            self.parse.iterations, self.warmup
+        )?;
+        write!(
+            f,
+            "{:<8}{:>14}{:>14}{:>14}{:>14}",
+            "", "min", "median", "p95", "mean"
+        )?;
+        let rows = std::iter::once(("Parse".to_string(), &self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|part| (format!("Part {}", part.part), &part.stats)),
+        );
+        for (label, stats) in rows {
+            write!(
+                f,
+                "\n{label:<8}{:>14}{:>14}{:>14}{:>14}",
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+                format!("{:.2?}", stats.mean),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{Day, Solution, Unsolved};
+
+    static SOLVES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts how often its first part is solved.
+    struct Counted;
+
+    impl Solution for Counted {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Counted";
+
+        type Input = ();
+        type PartOne = u64;
+        type PartTwo = Unsolved;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> u64 {
+            SOLVES.fetch_add(1, Ordering::Relaxed) as u64
+        }
+
+        fn part_two(_input: &Self::Input) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_p95_ignores_slowest_five_percent() {
+        let samples = (1..=100).collect::<Vec<u64>>();
+        let stats = Stats::from_samples(millis(&samples));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let stats = measure(
+            BenchOptions {
+                warmup: 3,
+                iterations: 7,
+            },
+            || calls += 1,
+        );
+        assert_eq!(calls, 10);
+        assert_eq!(stats.iterations, 7);
+    }
+
+    #[test]
+    fn test_bench_only_runs_the_measured_solves() {
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 3,
+        };
+        let benchmark = bench(&Day::<Counted>::new(), "", &Part::ALL, options).unwrap();
+        assert_eq!(SOLVES.load(Ordering::Relaxed), 5);
+        assert_eq!(benchmark.parts.len(), 1);
+        assert_eq!(benchmark.parts[0].part, Part::One);
+    }
+
+    #[test]
+    fn test_stats_serialize_as_nanoseconds() {
+        let stats = Stats::from_samples(millis(&[2]));
+        let json = serde_json::to_value(stats).unwrap();
+        assert_eq!(json["min_ns"], 2_000_000);
+        assert_eq!(json["p95_ns"], 2_000_000);
+    }
+}
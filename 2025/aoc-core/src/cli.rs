@@ -0,0 +1,55 @@
+use std::{error::Error, path::Path, process::ExitCode};
+
+use clap::{Args, Parser};
+
+use crate::{Day, InputSource, Part, Report, Solution};
+
+/// Options shared by every way of running a day: the `aoc` runner and each day's own binary.
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Only run the given part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+    /// Read the puzzle input from this file, or `-` for stdin
+    #[arg(long, short)]
+    pub input: Option<String>,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    pub fn input_source(&self, year: u16, day: u8, day_dir: &Path) -> InputSource {
+        InputSource::resolve(self.input.as_deref(), year, day, day_dir)
+    }
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    args: RunArgs,
+}
+
+/// Entry point for a day's binary; `day_dir` is the day crate's `CARGO_MANIFEST_DIR`.
+pub fn main<S: Solution>(day_dir: &str) -> ExitCode {
+    let cli = DayCli::parse();
+    match run_day::<S>(&cli.args, Path::new(day_dir)) {
+        Ok(report) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_day<S: Solution>(args: &RunArgs, day_dir: &Path) -> Result<Report, Box<dyn Error>> {
+    let input = args.input_source(S::YEAR, S::DAY, day_dir).read(S::DAY)?;
+    Ok(crate::run(&Day::<S>::new(), &input, &args.parts())?)
+}
@@ -0,0 +1,10 @@
+use std::{env, path::PathBuf};
+
+/// The `aoc` directory in the user's config directory, where the session
+/// config and the input key live: `$XDG_CONFIG_HOME/aoc`, otherwise `~/.config/aoc`.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("aoc"))
+}
@@ -0,0 +1,222 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    rand::{SecureRandom, SystemRandom},
+};
+
+use crate::config_dir;
+
+/// Overrides where the input key is read from.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Starts every encrypted input, so other files are not mistaken for one.
+const MAGIC: &[u8] = b"aoc-input-v1\n";
+
+const KEY_LEN: usize = 32;
+
+/// The secret that puzzle inputs are encrypted with, kept out of the
+/// repository as a hex string in a local key file.
+#[derive(Clone)]
+pub struct InputKey([u8; KEY_LEN]);
+
+impl InputKey {
+    pub fn generate() -> Result<Self, CryptError> {
+        let mut bytes = [0; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| CryptError::Random)?;
+        Ok(Self(bytes))
+    }
+
+    /// [`KEY_VAR`] if set, otherwise `aoc/input.key` in the user's config directory.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(KEY_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        Some(config_dir()?.join("input.key"))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, CryptError> {
+        let contents = fs::read_to_string(path).map_err(|error| CryptError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        Self::from_hex(contents.trim()).ok_or_else(|| CryptError::InvalidKey(path.to_path_buf()))
+    }
+
+    /// Writes the key to a new file at `path`, readable only by its owner on Unix.
+    pub fn save(&self, path: &Path) -> Result<(), CryptError> {
+        let io_error = |error| CryptError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path).map_err(io_error)?;
+        io::Write::write_all(&mut file, format!("{}\n", self.to_hex()).as_bytes()).map_err(io_error)
+    }
+
+    fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != KEY_LEN * 2 || !hex.is_ascii() {
+            return None;
+        }
+        let mut bytes = [0; KEY_LEN];
+        for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+        }
+        Some(Self(bytes))
+    }
+
+    fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn cipher(&self) -> LessSafeKey {
+        LessSafeKey::new(
+            UnboundKey::new(&AES_256_GCM, &self.0).expect("Key has the AES-256 length!"),
+        )
+    }
+
+    /// `input` sealed with AES-256-GCM under a fresh random nonce.
+    pub fn encrypt(&self, input: &str) -> Result<Vec<u8>, CryptError> {
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| CryptError::Random)?;
+        let mut sealed = input.as_bytes().to_vec();
+        self.cipher()
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .expect("Inputs are far below the AES-GCM size limit!");
+        Ok([MAGIC, &nonce, &sealed].concat())
+    }
+
+    pub fn decrypt(&self, encrypted: &[u8]) -> Result<String, CryptError> {
+        let rest = encrypted
+            .strip_prefix(MAGIC)
+            .ok_or(CryptError::NotEncrypted)?;
+        if rest.len() < NONCE_LEN {
+            return Err(CryptError::NotEncrypted);
+        }
+        let (nonce, sealed) = rest.split_at(NONCE_LEN);
+        let nonce =
+            Nonce::try_assume_unique_for_key(nonce).map_err(|_| CryptError::NotEncrypted)?;
+        let mut sealed = sealed.to_vec();
+        let opened = self
+            .cipher()
+            .open_in_place(nonce, Aad::empty(), &mut sealed)
+            .map_err(|_| CryptError::WrongKey)?;
+        String::from_utf8(opened.to_vec()).map_err(|_| CryptError::NotEncrypted)
+    }
+}
+
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InputKey(..)")
+    }
+}
+
+/// Where the encrypted copy of the input at `path` is kept: `input.txt.enc` for `input.txt`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(".enc");
+    PathBuf::from(encrypted)
+}
+
+#[derive(Debug)]
+pub enum CryptError {
+    /// Neither [`KEY_VAR`] nor a home directory says where the key is.
+    NoKeyPath,
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    InvalidKey(PathBuf),
+    NotEncrypted,
+    /// The key does not match, or the file was modified.
+    WrongKey,
+    Random,
+}
+
+impl fmt::Display for CryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoKeyPath => write!(f, "cannot locate the input key, set {KEY_VAR} to its path"),
+            Self::Io { path, error } => write!(f, "failed to access {}: {error}", path.display()),
+            Self::InvalidKey(path) => write!(
+                f,
+                "{} does not hold a key of {} hex digits",
+                path.display(),
+                KEY_LEN * 2
+            ),
+            Self::NotEncrypted => write!(f, "not an encrypted input"),
+            Self::WrongKey => write!(f, "the key does not match or the file was modified"),
+            Self::Random => write!(f, "the system random number generator failed"),
+        }
+    }
+}
+
+impl std::error::Error for CryptError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_round_trips_and_hides_the_input() {
+        let key = InputKey::generate().unwrap();
+        let input = "L68\nR48\n";
+        let encrypted = key.encrypt(input).unwrap();
+        assert!(!encrypted.windows(3).any(|window| window == b"L68"));
+        assert_ne!(encrypted, key.encrypt(input).unwrap());
+        assert_eq!(key.decrypt(&encrypted).unwrap(), input);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_other_keys_and_tampering() {
+        let key = InputKey::generate().unwrap();
+        let mut encrypted = key.encrypt("L68\n").unwrap();
+        let other = InputKey::generate().unwrap();
+        assert!(matches!(
+            other.decrypt(&encrypted),
+            Err(CryptError::WrongKey)
+        ));
+        *encrypted.last_mut().unwrap() ^= 1;
+        assert!(matches!(key.decrypt(&encrypted), Err(CryptError::WrongKey)));
+        assert!(matches!(
+            key.decrypt(b"L68\n"),
+            Err(CryptError::NotEncrypted)
+        ));
+    }
+
+    #[test]
+    fn test_key_file_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc/input.key");
+        let key = InputKey::generate().unwrap();
+        key.save(&path).unwrap();
+        assert_eq!(InputKey::load(&path).unwrap().0, key.0);
+        assert!(
+            key.save(&path).is_err(),
+            "an existing key is never overwritten"
+        );
+
+        fs::write(&path, "not hex").unwrap();
+        assert!(matches!(
+            InputKey::load(&path),
+            Err(CryptError::InvalidKey(_))
+        ));
+    }
+}
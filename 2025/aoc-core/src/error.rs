@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// A malformed puzzle input, pointing at the offending line and column.
+/// Its `Display` output includes the source line with the bad token underlined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    source_line: String,
+    width: usize,
+}
+
+impl ParseError {
+    /// An error at `span`, which must be a slice of `input` (as returned by
+    /// `lines`, `split`, `trim` and friends). Spans from elsewhere point at the end of input.
+    pub fn at(input: &str, span: &str, expected: impl Into<String>) -> Self {
+        let start = span.as_ptr() as usize;
+        let base = input.as_ptr() as usize;
+        match start.checked_sub(base) {
+            Some(offset) if offset + span.len() <= input.len() => {
+                Self::new(input, offset, span.len(), expected)
+            }
+            _ => Self::at_end(input, expected),
+        }
+    }
+
+    /// An error for input that stopped before something required appeared.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(input.trim_end(), input.trim_end().len(), 0, expected)
+    }
+
+    /// An error covering `len` bytes starting at byte `offset` of `input`.
+    pub fn new(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+        let found = match input.get(offset..offset + len).unwrap_or_default() {
+            "" => input[offset..line_end].chars().next().map(String::from),
+            span => Some(span.to_string()),
+        };
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: found
+                .as_ref()
+                .map_or("end of line".to_string(), |found| format!("`{found}`")),
+            source_line: source_line.to_string(),
+            width: found.map_or(1, |found| found.chars().count()),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "expected {}, found {} at line {}, column {}",
+            self.expected, self.found, self.line, self.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_points_to_span_in_input() {
+        let input = "3-5\n10x14\n16-20";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[2..3], "`-`");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "`x`");
+    }
+
+    #[test]
+    fn test_display_renders_snippet() {
+        let input = "11-22,95x115";
+        let err = ParseError::at(input, &input[6..], "`-` between range bounds");
+        assert_eq!(
+            err.to_string(),
+            "expected `-` between range bounds, found `95x115` at line 1, column 7\n  |\n1 | 11-22,95x115\n  |       ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_empty_span_reports_end_of_line() {
+        let input = "3-5\n";
+        let err = ParseError::at(input, &input[3..3], "a blank line");
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.found, "end of line");
+    }
+
+    #[test]
+    fn test_empty_span_mid_line_reports_next_character() {
+        let input = "-5-10";
+        let err = ParseError::at(input, &input[..0], "a number");
+        assert_eq!(err.found, "`-`");
+    }
+
+    #[test]
+    fn test_foreign_span_points_at_end_of_input() {
+        let input = "3-5\n10-14\n";
+        let err = ParseError::at(input, "elsewhere", "a blank line");
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Answer, Day, Part, Puzzle, Solution};
+
+/// A sample input from a day's `examples/` directory. `sample.txt` holds the
+/// input and an optional `sample.toml` next to it the answers it should give:
+///
+/// ```toml
+/// part1 = "3"
+/// part2 = 14
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: BTreeMap<Part, String>,
+}
+
+impl Example {
+    /// Every `*.txt` example in `dir`, sorted by name.
+    pub fn load_all(dir: &Path) -> Result<Vec<Self>, ExampleError> {
+        let io_error = |source| ExampleError::Io {
+            path: dir.to_path_buf(),
+            source,
+        };
+        let mut inputs = fs::read_dir(dir)
+            .map_err(io_error)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<PathBuf>, io::Error>>()
+            .map_err(io_error)?;
+        inputs.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+        inputs.sort();
+        inputs.iter().map(|path| Self::load(path)).collect()
+    }
+
+    fn load(path: &Path) -> Result<Self, ExampleError> {
+        let read = |path: &Path| {
+            fs::read_to_string(path).map_err(|source| ExampleError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+        };
+        let answers_path = path.with_extension("toml");
+        let expected = if answers_path.exists() {
+            parse_expected(&read(&answers_path)?).map_err(|message| ExampleError::Format {
+                path: answers_path,
+                message,
+            })?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            name: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            input: read(path)?,
+            expected,
+        })
+    }
+
+    /// Parses and solves the example, describing every way it disagrees with
+    /// its expected answers.
+    pub fn check(&self, puzzle: &dyn Puzzle) -> Vec<String> {
+        let parsed = match puzzle.parse(&self.input) {
+            Ok(parsed) => parsed,
+            Err(err) => return vec![format!("{}: {err}", self.name)],
+        };
+        self.expected
+            .iter()
+            .filter_map(|(&part, expected)| {
+                let answer = puzzle.solve(parsed.as_ref(), part);
+                match &answer {
+                    Answer::Solved(actual) if actual == expected => None,
+                    _ => Some(format!(
+                        "{} part {part}: expected {expected}, got {answer}",
+                        self.name
+                    )),
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_expected(contents: &str) -> Result<BTreeMap<Part, String>, String> {
+    let table: toml::Table = toml::from_str(contents).map_err(|err| err.to_string())?;
+    table
+        .into_iter()
+        .map(|(key, value)| {
+            let part = Part::ALL
+                .into_iter()
+                .find(|part| key == format!("part{part}"))
+                .ok_or_else(|| format!("unknown key `{key}`, expected `part1` or `part2`"))?;
+            let answer = match value {
+                toml::Value::String(answer) => answer,
+                toml::Value::Integer(answer) => answer.to_string(),
+                other => return Err(format!("`{key}` must be a string or integer, not {other}")),
+            };
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+/// Runs every example in `<day_dir>/examples` against `S`, panicking with all
+/// failures at once. Meant to be called from a day's tests with
+/// `env!("CARGO_MANIFEST_DIR")`.
+pub fn check_examples<S: Solution>(day_dir: &str) {
+    let dir = Path::new(day_dir).join("examples");
+    let examples = Example::load_all(&dir).unwrap_or_else(|err| panic!("{err}"));
+    assert!(!examples.is_empty(), "no examples in {}", dir.display());
+    let failures: Vec<String> = examples
+        .iter()
+        .flat_map(|example| example.check(&Day::<S>::new()))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[derive(Debug)]
+pub enum ExampleError {
+    Io { path: PathBuf, source: io::Error },
+    Format { path: PathBuf, message: String },
+}
+
+impl fmt::Display for ExampleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "failed to read {}: {source}", path.display()),
+            Self::Format { path, message } => write!(f, "invalid {}: {message}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ExampleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    struct Double;
+
+    impl Solution for Double {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Double";
+
+        type Input = u64;
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(input: &str) -> Result<u64, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::at(input, input.trim(), "a number"))
+        }
+
+        fn part_one(n: &u64) -> u64 {
+            n * 2
+        }
+
+        fn part_two(n: &u64) -> u64 {
+            n * 4
+        }
+    }
+
+    fn example(dir: &Path, name: &str, input: &str, answers: Option<&str>) {
+        fs::write(dir.join(format!("{name}.txt")), input).unwrap();
+        if let Some(answers) = answers {
+            fs::write(dir.join(format!("{name}.toml")), answers).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_load_all_pairs_inputs_with_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        example(dir.path(), "b", "2\n", None);
+        example(dir.path(), "a", "1\n", Some("part1 = \"2\"\npart2 = 4\n"));
+        let examples = Example::load_all(dir.path()).unwrap();
+        assert_eq!(
+            examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(examples[0].expected[&Part::Two], "4");
+        assert!(examples[1].expected.is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_wrong_answers_and_parse_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        example(dir.path(), "good", "3", Some("part1 = 6\npart2 = 12"));
+        example(dir.path(), "wrong", "3", Some("part2 = 13"));
+        example(dir.path(), "broken", "x", None);
+        let puzzle = Day::<Double>::new();
+        let failures: Vec<String> = Example::load_all(dir.path())
+            .unwrap()
+            .iter()
+            .flat_map(|example| example.check(&puzzle))
+            .collect();
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].starts_with("broken: expected a number"));
+        assert_eq!(failures[1], "wrong part 2: expected 13, got 12");
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys() {
+        assert!(parse_expected("part3 = 1").is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// A directed graph with weighted edges, keyed by any hashable node type.
+/// Nodes are stored once and referred to by index internally.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` if it is not already present.
+    pub fn add_node(&mut self, node: N) {
+        self.id(node);
+    }
+
+    /// Adds an edge of weight 1, creating either end if needed.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, from: N, to: N, weight: u64) {
+        let from = self.id(from);
+        let to = self.id(to);
+        self.edges[from].push((to, weight));
+    }
+
+    fn id(&mut self, node: N) -> usize {
+        if let Some(&id) = self.index.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.index.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// Every node in insertion order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// The nodes `node` has an edge to; empty for unknown nodes.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.edges(node).map(|(to, _)| to)
+    }
+
+    /// The outgoing edges of `node` with their weights.
+    pub fn edges(&self, node: &N) -> impl Iterator<Item = (&N, u64)> {
+        self.index
+            .get(node)
+            .map_or(&[][..], |&id| &self.edges[id])
+            .iter()
+            .map(|&(to, weight)| (&self.nodes[to], weight))
+    }
+
+    /// Every node ordered so that edges only point forwards, or `None` if the
+    /// graph has a cycle.
+    pub fn topological_order(&self) -> Option<Vec<&N>> {
+        self.topological_ids()
+            .map(|ids| ids.into_iter().map(|id| &self.nodes[id]).collect())
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.topological_ids().is_none()
+    }
+
+    fn topological_ids(&self) -> Option<Vec<usize>> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for &(to, _) in self.edges.iter().flatten() {
+            in_degree[to] += 1;
+        }
+        let mut ready: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&id| in_degree[id] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+            for &(to, _) in &self.edges[id] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// Every node reachable from `start`, including `start` itself.
+    pub fn reachable(&self, start: &N) -> HashSet<&N> {
+        self.bfs(start).into_keys().collect()
+    }
+
+    /// The number of edges on the shortest path from `start` to every node it reaches.
+    pub fn bfs(&self, start: &N) -> HashMap<&N, usize> {
+        let mut distances = HashMap::new();
+        let Some(&start) = self.index.get(start) else {
+            return distances;
+        };
+        let mut seen = vec![false; self.nodes.len()];
+        seen[start] = true;
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((id, distance)) = queue.pop_front() {
+            distances.insert(&self.nodes[id], distance);
+            for &(to, _) in &self.edges[id] {
+                if !seen[to] {
+                    seen[to] = true;
+                    queue.push_back((to, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// The total weight of the lightest path from `start` to every node it reaches.
+    pub fn dijkstra(&self, start: &N) -> HashMap<&N, u64> {
+        self.shortest_paths(start)
+            .into_iter()
+            .map(|(id, (distance, _))| (&self.nodes[id], distance))
+            .collect()
+    }
+
+    /// The weight and nodes of the lightest path from `from` to `to`.
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(u64, Vec<&N>)> {
+        let target = *self.index.get(to)?;
+        let paths = self.shortest_paths(from);
+        let (distance, _) = *paths.get(&target)?;
+        let mut path = vec![&self.nodes[target]];
+        let mut current = target;
+        while let Some(&(_, Some(previous))) = paths.get(&current) {
+            path.push(&self.nodes[previous]);
+            current = previous;
+        }
+        path.reverse();
+        Some((distance, path))
+    }
+
+    /// Distance and predecessor of every node reachable from `start`, by id.
+    fn shortest_paths(&self, start: &N) -> HashMap<usize, (u64, Option<usize>)> {
+        let mut best = HashMap::new();
+        let Some(&start) = self.index.get(start) else {
+            return best;
+        };
+        best.insert(start, (0, None));
+        let mut queue = BinaryHeap::from([Reverse((0u64, start))]);
+        while let Some(Reverse((distance, id))) = queue.pop() {
+            if best.get(&id).is_some_and(|&(known, _)| known < distance) {
+                continue;
+            }
+            for &(to, weight) in &self.edges[id] {
+                let candidate = distance + weight;
+                if best.get(&to).is_none_or(|&(known, _)| candidate < known) {
+                    best.insert(to, (candidate, Some(id)));
+                    queue.push(Reverse((candidate, to)));
+                }
+            }
+        }
+        best
+    }
+
+    /// How many distinct paths lead from `from` to `to`, or `None` if a cycle
+    /// is reachable from `from` or the count does not fit in a `u128`.
+    pub fn count_paths(&self, from: &N, to: &N) -> Option<u128> {
+        self.count_paths_where(from, |node| node == to)
+    }
+
+    /// How many distinct paths lead from `from` to any node matching `is_end`,
+    /// or `None` if a cycle is reachable from `from` or the count does not fit
+    /// in a `u128`. Paths continue through end nodes, so an end reachable via
+    /// another end is counted on both paths. Nodes `from` cannot reach are
+    /// never visited.
+    pub fn count_paths_where(&self, from: &N, mut is_end: impl FnMut(&N) -> bool) -> Option<u128> {
+        let Some(&from) = self.index.get(from) else {
+            return Some(0);
+        };
+        // Depth-first with an explicit stack, so long chains cannot overflow
+        // the call stack; a node's count is known once all its successors are.
+        let mut paths: Vec<Option<u128>> = vec![None; self.nodes.len()];
+        let mut on_stack = vec![false; self.nodes.len()];
+        let mut stack = vec![(from, 0)];
+        on_stack[from] = true;
+        while let Some((id, next)) = stack.last_mut() {
+            let id = *id;
+            if let Some(&(to, _)) = self.edges[id].get(*next) {
+                *next += 1;
+                if on_stack[to] {
+                    return None;
+                }
+                if paths[to].is_none() {
+                    on_stack[to] = true;
+                    stack.push((to, 0));
+                }
+                continue;
+            }
+            stack.pop();
+            on_stack[id] = false;
+            let mut total = u128::from(is_end(&self.nodes[id]));
+            for &(to, _) in &self.edges[id] {
+                total = total.checked_add(paths[to].expect("Successors are counted first!"))?;
+            }
+            paths[id] = Some(total);
+        }
+        paths[from]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("a", "b", 1);
+        graph.add_weighted_edge("a", "c", 4);
+        graph.add_weighted_edge("b", "c", 2);
+        graph.add_weighted_edge("b", "d", 6);
+        graph.add_weighted_edge("c", "d", 1);
+        graph
+    }
+
+    #[test]
+    fn test_nodes_and_edges() {
+        let mut graph = diamond();
+        graph.add_node("a");
+        graph.add_node("lonely");
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(graph.successors(&"b").collect::<Vec<_>>(), [&"c", &"d"]);
+        assert_eq!(graph.successors(&"missing").count(), 0);
+    }
+
+    #[test]
+    fn test_topological_order_and_cycles() {
+        let mut graph = diamond();
+        let order = graph.topological_order().unwrap();
+        let position = |node| order.iter().position(|&&n| n == node).unwrap();
+        assert!(position("a") < position("b") && position("b") < position("c"));
+        assert!(!graph.has_cycle());
+
+        graph.add_edge("d", "a");
+        assert!(graph.has_cycle());
+        assert_eq!(graph.topological_order(), None);
+        assert_eq!(graph.count_paths(&"a", &"d"), None);
+    }
+
+    #[test]
+    fn test_reachability_and_bfs() {
+        let mut graph = diamond();
+        graph.add_edge("x", "a");
+        assert_eq!(graph.reachable(&"b"), HashSet::from([&"b", &"c", &"d"]));
+        let distances = graph.bfs(&"a");
+        assert_eq!((distances[&"d"], distances.len()), (2, 4));
+    }
+
+    #[test]
+    fn test_dijkstra_and_shortest_path() {
+        let graph = diamond();
+        assert_eq!(graph.dijkstra(&"a")[&"d"], 4);
+        assert_eq!(
+            graph.shortest_path(&"a", &"d"),
+            Some((4, vec![&"a", &"b", &"c", &"d"]))
+        );
+        assert_eq!(graph.shortest_path(&"d", &"a"), None);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = diamond();
+        assert_eq!(graph.count_paths(&"a", &"d"), Some(3));
+        assert_eq!(graph.count_paths(&"d", &"a"), Some(0));
+        let leaves = graph.count_paths_where(&"a", |node| graph.successors(node).count() == 0);
+        assert_eq!(leaves, Some(3));
+    }
+
+    #[test]
+    fn test_count_paths_ignores_cycles_it_cannot_reach() {
+        let mut graph = diamond();
+        graph.add_edge("x", "y");
+        graph.add_edge("y", "x");
+        graph.add_edge("x", "a");
+        assert!(graph.has_cycle());
+        assert_eq!(graph.count_paths(&"a", &"d"), Some(3));
+        assert_eq!(graph.count_paths(&"x", &"d"), None);
+    }
+
+    #[test]
+    fn test_count_paths_reports_overflow() {
+        // Each layer doubles the paths: 2^128 of them reach the last node.
+        let mut graph = Graph::new();
+        for layer in 0..128 {
+            graph.add_edge(layer * 2, layer * 2 + 2);
+            graph.add_edge(layer * 2, layer * 2 + 1);
+            graph.add_edge(layer * 2 + 1, layer * 2 + 2);
+        }
+        assert_eq!(graph.count_paths(&0, &254), Some(1 << 127));
+        assert_eq!(graph.count_paths(&0, &256), None);
+    }
+}
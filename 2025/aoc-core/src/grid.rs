@@ -0,0 +1,367 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// A cell position: `x` counts columns from the left, `y` rows from the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The point `(dx, dy)` away, or `None` if that would go left of or above the origin.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per non-blank line, mapping each character with `cell`.
+    /// Rows of different widths and characters `cell` rejects are reported
+    /// with their position; `expected` describes the characters `cell` accepts.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let line = line.trim_end_matches('\r');
+            let row_width = line.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a row of {} cells", width.unwrap_or_default()),
+                ));
+            }
+            for (index, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(input, &line[index..index + c.len_utf8()], expected)
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Every point in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The up to four in-bounds points sharing an edge with `point`.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &ORTHOGONAL)
+    }
+
+    /// The up to eight in-bounds points sharing an edge or corner with `point`.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &ADJACENT)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| point.offset(dx, dy))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// The first cell, in row-major order, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// Where `value` first appears, in row-major order.
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// Every point whose cell matches `predicate`.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| {
+            Point::new(y, self.height - 1 - x)
+        })
+    }
+
+    /// Turns the grid a quarter turn counter-clockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| {
+            Point::new(self.width - 1 - y, x)
+        })
+    }
+
+    /// A `width` by `height` grid whose cell `(x, y)` is copied from `source(x, y)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some, "a letter").unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn test_parse_reports_ragged_rows_and_bad_cells() {
+        let err = Grid::parse("..\n...", Some, "a cell").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a row of 2 cells"));
+
+        let err = Grid::parse(".#\n..", |c| (c == '.').then_some(c), "`.`").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+    }
+
+    #[test]
+    fn test_neighbors_stay_in_bounds() {
+        let grid = sample();
+        let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_find_and_positions() {
+        let grid = Grid::parse(".S.\n^.^", Some, "a cell").unwrap();
+        assert_eq!(grid.position(&'S'), Some(Point::new(1, 0)));
+        assert_eq!(
+            grid.positions(|&c| c == '^').collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn test_from_rows_rejects_ragged_rows() {
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(grid.map(|n| n * 10)[Point::new(0, 1)], 30);
+    }
+}
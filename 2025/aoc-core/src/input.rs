@@ -0,0 +1,301 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{CryptError, InputKey, encrypted_path};
+
+/// Text left in `input.txt` by the day template until the real input is pasted in.
+pub const PLACEHOLDER: &str = "Paste your puzzle input here";
+
+/// Directory holding inputs as `<year>/dayNN.txt`, checked before a day's own `input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// An explicit `--input` wins (`-` meaning stdin), then [`INPUT_DIR_VAR`],
+    /// then `input.txt` inside the day's crate directory.
+    pub fn resolve(arg: Option<&str>, year: u16, day: u8, day_dir: &Path) -> Self {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve_with(arg, input_dir.as_deref(), year, day, day_dir)
+    }
+
+    fn resolve_with(
+        arg: Option<&str>,
+        input_dir: Option<&Path>,
+        year: u16,
+        day: u8,
+        day_dir: &Path,
+    ) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+            None => Self::File(default_path_with(input_dir, year, day, day_dir)),
+        }
+    }
+
+    /// Where the input lives when no `--input` is given.
+    pub fn default_path(year: u16, day: u8, day_dir: &Path) -> PathBuf {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        default_path_with(input_dir.as_deref(), year, day, day_dir)
+    }
+
+    /// Reads the input for `day`, rejecting missing, empty and placeholder inputs
+    /// before they reach a parser. A file that is missing or still a placeholder
+    /// falls back to its encrypted copy, decrypted with the [`InputKey`].
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        self.read_with(day, InputKey::default_path().as_deref())
+    }
+
+    fn read_with(&self, day: u8, key_path: Option<&Path>) -> Result<String, InputError> {
+        let err = match self.read_plain(day) {
+            Err(err @ InputError::NotProvided { .. }) => err,
+            result => return result,
+        };
+        match self {
+            Self::File(path) if encrypted_path(path).exists() => {
+                self.read_encrypted(day, &encrypted_path(path), key_path)
+            }
+            _ => Err(err),
+        }
+    }
+
+    fn read_plain(&self, day: u8) -> Result<String, InputError> {
+        let result = match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Self::File(path) => fs::read_to_string(path),
+        };
+        let input = result.map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => self.not_provided(day, MissingInput::NotFound),
+            _ => InputError::Io {
+                source: self.clone(),
+                error,
+            },
+        })?;
+        match check(&input) {
+            Some(reason) => Err(self.not_provided(day, reason)),
+            None => Ok(input),
+        }
+    }
+
+    fn read_encrypted(
+        &self,
+        day: u8,
+        path: &Path,
+        key_path: Option<&Path>,
+    ) -> Result<String, InputError> {
+        let decrypt = || {
+            let encrypted = fs::read(path).map_err(|error| CryptError::Io {
+                path: path.to_path_buf(),
+                error,
+            })?;
+            InputKey::load(key_path.ok_or(CryptError::NoKeyPath)?)?.decrypt(&encrypted)
+        };
+        let input = decrypt().map_err(|error| InputError::Decrypt {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        match check(&input) {
+            Some(reason) => Err(InputSource::File(path.to_path_buf()).not_provided(day, reason)),
+            None => Ok(input),
+        }
+    }
+
+    fn not_provided(&self, day: u8, reason: MissingInput) -> InputError {
+        InputError::NotProvided {
+            day,
+            source: self.clone(),
+            reason,
+        }
+    }
+}
+
+fn default_path_with(input_dir: Option<&Path>, year: u16, day: u8, day_dir: &Path) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(year.to_string()).join(format!("day{day:02}.txt")),
+        None => day_dir.join("input.txt"),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingInput {
+    NotFound,
+    Empty,
+    Placeholder,
+}
+
+/// Returns why `input` cannot be a real puzzle input, if it cannot.
+pub fn check(input: &str) -> Option<MissingInput> {
+    if input.trim().is_empty() {
+        Some(MissingInput::Empty)
+    } else if input.contains(PLACEHOLDER) {
+        Some(MissingInput::Placeholder)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "does not exist"),
+            Self::Empty => write!(f, "is empty"),
+            Self::Placeholder => write!(f, "still holds the template placeholder"),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "stdin"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        source: InputSource,
+        error: io::Error,
+    },
+    NotProvided {
+        day: u8,
+        source: InputSource,
+        reason: MissingInput,
+    },
+    Decrypt {
+        path: PathBuf,
+        error: CryptError,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { source, error } => write!(f, "failed to read input from {source}: {error}"),
+            Self::NotProvided {
+                day,
+                source,
+                reason,
+            } => write!(f, "input not provided for day {day} ({source} {reason})"),
+            Self::Decrypt { path, error } => {
+                write!(f, "failed to decrypt {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_path_wins_over_input_dir() {
+        let source = InputSource::resolve_with(
+            Some("custom.txt"),
+            Some(Path::new("/inputs")),
+            2025,
+            5,
+            Path::new("/repo/day05"),
+        );
+        assert_eq!(source, InputSource::File(PathBuf::from("custom.txt")));
+    }
+
+    #[test]
+    fn test_dash_reads_stdin() {
+        let source = InputSource::resolve_with(Some("-"), None, 2025, 5, Path::new("day05"));
+        assert_eq!(source, InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_input_dir_uses_year_and_padded_day() {
+        let source = InputSource::resolve_with(
+            None,
+            Some(Path::new("/inputs")),
+            2025,
+            5,
+            Path::new("day05"),
+        );
+        assert_eq!(
+            source,
+            InputSource::File(PathBuf::from("/inputs/2025/day05.txt"))
+        );
+    }
+
+    #[test]
+    fn test_defaults_to_day_directory() {
+        let source = InputSource::resolve_with(None, None, 2025, 5, Path::new("/repo/day05"));
+        assert_eq!(
+            source,
+            InputSource::File(PathBuf::from("/repo/day05/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_not_provided() {
+        let source = InputSource::File(PathBuf::from("/nonexistent/day05/input.txt"));
+        let err = source.read(5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input not provided for day 5 (/nonexistent/day05/input.txt does not exist)"
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_the_encrypted_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let (input, key_path) = (dir.path().join("input.txt"), dir.path().join("input.key"));
+        let source = InputSource::File(input.clone());
+        let key = InputKey::generate().unwrap();
+        key.save(&key_path).unwrap();
+        fs::write(encrypted_path(&input), key.encrypt("3-5\n\n1\n").unwrap()).unwrap();
+
+        assert_eq!(source.read_with(5, Some(&key_path)).unwrap(), "3-5\n\n1\n");
+        fs::write(&input, PLACEHOLDER).unwrap();
+        assert_eq!(source.read_with(5, Some(&key_path)).unwrap(), "3-5\n\n1\n");
+        fs::write(&input, "1-2\n\n1\n").unwrap();
+        assert_eq!(source.read_with(5, Some(&key_path)).unwrap(), "1-2\n\n1\n");
+
+        fs::remove_file(&input).unwrap();
+        let err = source.read_with(5, None).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::Decrypt {
+                error: CryptError::NoKeyPath,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_check_rejects_empty_input() {
+        assert_eq!(check(""), Some(MissingInput::Empty));
+        assert_eq!(check("  \n\t\n"), Some(MissingInput::Empty));
+    }
+
+    #[test]
+    fn test_check_rejects_placeholder() {
+        assert_eq!(
+            check("Paste your puzzle input here\n"),
+            Some(MissingInput::Placeholder)
+        );
+    }
+
+    #[test]
+    fn test_check_accepts_puzzle_input() {
+        assert_eq!(check("3-5\n10-14\n\n1\n5\n"), None);
+    }
+}
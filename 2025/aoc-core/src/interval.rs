@@ -0,0 +1,203 @@
+use std::ops::RangeInclusive;
+
+/// A set of `u64`s stored as sorted, disjoint inclusive ranges. Overlapping
+/// and touching ranges are merged as they are inserted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`; an empty range changes nothing.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|&(_, existing_end)| existing_end.saturating_add(1) < start);
+        let last = self
+            .intervals
+            .partition_point(|&(existing_start, _)| existing_start <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Binary-searches for the interval that could hold `value`.
+    pub fn contains(&self, value: u64) -> bool {
+        let index = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values the set holds, saturating at `u64::MAX`.
+    pub fn size(&self) -> u64 {
+        self.intervals.iter().fold(0u64, |total, &(start, end)| {
+            total.saturating_add((end - start).saturating_add(1))
+        })
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The values missing between the first and last range, as ranges.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| pair[0].1 + 1..=pair[1].0 - 1)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (left.peek(), right.peek())
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a_end < b_end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut removed = other.intervals.iter().peekable();
+        for &(start, end) in &self.intervals {
+            let mut start = Some(start);
+            while let (Some(from), Some(&&(cut_start, cut_end))) = (start, removed.peek()) {
+                if cut_end < from {
+                    removed.next();
+                    continue;
+                }
+                if cut_start > end {
+                    break;
+                }
+                if cut_start > from {
+                    intervals.push((from, cut_start - 1));
+                }
+                start = cut_end.checked_add(1).filter(|&next| next <= end);
+                if cut_end <= end {
+                    removed.next();
+                }
+            }
+            if let Some(from) = start {
+                intervals.push((from, end));
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<u64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet) -> Vec<RangeInclusive<u64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_touching_ranges() {
+        let fresh = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(ranges(&fresh), [3..=5, 10..=20]);
+        assert_eq!(fresh.size(), 14);
+
+        let touching = set(&[1..=2, 3..=4, 8..=9, 0..=0]);
+        assert_eq!(ranges(&touching), [0..=4, 8..=9]);
+    }
+
+    #[test]
+    fn test_ignores_empty_ranges() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = set(&[5..=4]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let fresh = set(&[3..=5, 10..=20]);
+        let found: Vec<u64> = [1, 3, 5, 8, 11, 17, 32]
+            .into_iter()
+            .filter(|&id| fresh.contains(id))
+            .collect();
+        assert_eq!(found, [3, 5, 11, 17]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let fresh = set(&[3..=5, 10..=20, 22..=22]);
+        assert_eq!(fresh.gaps().collect::<Vec<_>>(), [6..=9, 21..=21]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25, 40..=50]);
+        assert_eq!(ranges(&a.union(&b)), [0..=30, 40..=50]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19, 40..=50]);
+    }
+
+    #[test]
+    fn test_difference_splits_ranges() {
+        let a = set(&[0..=20]);
+        let b = set(&[2..=3, 5..=5, 18..=u64::MAX]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=1, 4..=4, 6..=17]);
+    }
+
+    #[test]
+    fn test_handles_the_full_range() {
+        let all = set(&[0..=u64::MAX, 7..=9]);
+        assert_eq!(ranges(&all), [0..=u64::MAX]);
+        assert_eq!(all.size(), u64::MAX);
+        assert!(all.difference(&all).is_empty());
+    }
+}
@@ -0,0 +1,35 @@
+mod alloc;
+mod answer;
+mod answers;
+mod bench;
+pub mod cli;
+mod config;
+mod crypt;
+mod error;
+mod examples;
+mod graph;
+mod grid;
+mod input;
+mod interval;
+#[cfg(feature = "oracle")]
+pub mod oracle;
+pub mod parse;
+mod report;
+mod rng;
+mod solution;
+
+pub use alloc::{AllocStats, track};
+pub use answer::{Answer, Unsolved};
+pub use answers::{Answers, AnswersError, Verdict};
+pub use bench::{BenchOptions, Benchmark, PartStats, Stats, bench, measure};
+pub use config::config_dir;
+pub use crypt::{CryptError, InputKey, KEY_VAR, encrypted_path};
+pub use error::ParseError;
+pub use examples::{Example, ExampleError, check_examples};
+pub use graph::Graph;
+pub use grid::{Grid, Point};
+pub use input::{INPUT_DIR_VAR, InputError, InputSource, MissingInput, PLACEHOLDER, check};
+pub use interval::IntervalSet;
+pub use report::{PartResult, Report, run};
+pub use rng::Rng;
+pub use solution::{Day, Part, Puzzle, Solution};
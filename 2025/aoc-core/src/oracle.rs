@@ -0,0 +1,131 @@
+//! Randomised checks of a [`Solution`] against a slow but obviously-correct
+//! reference. Days enable the `oracle` feature of `aoc-core` in their
+//! dev-dependencies and build input strategies with the re-exported
+//! [`proptest`].
+
+use std::fmt::Debug;
+
+pub use proptest;
+use proptest::{
+    strategy::Strategy,
+    test_runner::{Config, TestCaseError, TestError, TestRunner},
+};
+
+use crate::{Answer, Part, Solution};
+
+/// Parses each input drawn from `inputs` and checks that `S` answers `part`
+/// the same as `reference`. A disagreement is shrunk to the smallest input
+/// `inputs` can produce and reported with both answers.
+///
+/// Runs 256 cases unless `PROPTEST_CASES` says otherwise.
+pub fn check_against<S, A>(
+    part: Part,
+    inputs: impl Strategy<Value = String>,
+    reference: impl Fn(&S::Input) -> A,
+) where
+    S: Solution,
+    A: Into<Answer>,
+{
+    check_inputs(inputs, |text| {
+        let input = S::parse(text)
+            .map_err(|err| TestCaseError::fail(format!("input does not parse: {err}")))?;
+        let actual: Answer = match part {
+            Part::One => S::part_one(&input).into(),
+            Part::Two => S::part_two(&input).into(),
+        };
+        let expected: Answer = reference(&input).into();
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(TestCaseError::fail(format!(
+                "day {:02} part {part} answered {actual}, the reference {expected}",
+                S::DAY
+            )))
+        }
+    });
+}
+
+/// Runs `check` on values drawn from `inputs`, panicking with the smallest
+/// failing value. For properties of a day's helpers rather than its answers.
+pub fn check_inputs<T: Debug>(
+    inputs: impl Strategy<Value = T>,
+    check: impl Fn(&T) -> Result<(), TestCaseError>,
+) {
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    match TestRunner::new(config).run(&inputs, |value| check(&value)) {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, value)) => panic!("{reason}\nminimal failing input: {value:?}"),
+        Err(TestError::Abort(reason)) => panic!("gave up: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::ParseError;
+
+    /// Sums the numbers on each line, but forgets any above 50.
+    struct Capped;
+
+    impl Solution for Capped {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Capped";
+
+        type Input = Vec<u64>;
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|line| crate::parse::number(input, line, "a number"))
+                .collect()
+        }
+
+        fn part_one(numbers: &Self::Input) -> u64 {
+            numbers.iter().sum()
+        }
+
+        fn part_two(numbers: &Self::Input) -> u64 {
+            numbers.iter().filter(|&&n| n <= 50).sum()
+        }
+    }
+
+    fn numbers() -> impl Strategy<Value = String> {
+        prop::collection::vec(0u64..100, 1..10).prop_map(|numbers| {
+            numbers
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    #[test]
+    fn test_agreeing_solution_passes() {
+        check_against::<Capped, _>(Part::One, numbers(), |numbers| numbers.iter().sum::<u64>());
+    }
+
+    #[test]
+    fn test_disagreement_is_shrunk() {
+        let panic = std::panic::catch_unwind(|| {
+            check_against::<Capped, _>(Part::Two, numbers(), |numbers| numbers.iter().sum::<u64>());
+        })
+        .unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(
+            message.contains("part 2 answered 0, the reference 51"),
+            "{message}"
+        );
+        assert!(
+            message.ends_with("minimal failing input: \"51\""),
+            "{message}"
+        );
+    }
+}
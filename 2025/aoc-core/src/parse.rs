@@ -0,0 +1,263 @@
+//! Parsers for the input shapes puzzles keep coming back to. Each takes the
+//! whole `input` alongside the part being parsed, so errors can point at the
+//! offending line and column; every `&str` handed back is a slice of `input`.
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{Grid, ParseError};
+
+/// Lines that hold more than whitespace, with any trailing `\r` removed.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim_end_matches('\r'))
+}
+
+/// The blocks of `input` separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(from) = start.take() {
+                sections.push(&input[from..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(from) = start {
+        sections.push(&input[from..end]);
+    }
+    sections
+}
+
+/// Exactly `N` blank-line separated sections; any extra sections belong to the last one.
+/// `expected` describes the separator that is missing when there are too few.
+pub fn split_sections<'a, const N: usize>(
+    input: &'a str,
+    expected: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let found = sections(input);
+    if found.len() < N {
+        return Err(ParseError::at_end(input, expected));
+    }
+    let mut split = [""; N];
+    split[..N - 1].copy_from_slice(&found[..N - 1]);
+    let last = &found[N - 1..];
+    split[N - 1] = last.first().map_or("", |first| {
+        let from = offset_in(input, first);
+        let to = last
+            .last()
+            .map_or(from, |last| offset_in(input, last) + last.len());
+        &input[from..to]
+    });
+    Ok(split)
+}
+
+fn offset_in(input: &str, span: &str) -> usize {
+    span.as_ptr() as usize - input.as_ptr() as usize
+}
+
+/// `span`, ignoring surrounding whitespace, parsed as a `T`.
+pub fn number<T: FromStr>(input: &str, span: &str, expected: &str) -> Result<T, ParseError> {
+    let span = span.trim();
+    span.parse()
+        .map_err(|_| ParseError::at(input, span, expected))
+}
+
+/// An inclusive range written `start-end`. `expected` describes the whole
+/// range and `bound` each end of it; ranges that end before they start are rejected.
+pub fn range<T: FromStr + PartialOrd>(
+    input: &str,
+    span: &str,
+    expected: &str,
+    bound: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let span = span.trim();
+    let (start, end) = span
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, span, expected))?;
+    let start: T = number(input, start, bound)?;
+    let end: T = number(input, end, bound)?;
+    if end < start {
+        return Err(ParseError::at(
+            input,
+            span,
+            "a range that ends after it starts",
+        ));
+    }
+    Ok(start..=end)
+}
+
+/// Parses every `separator`-delimited item of `span` with `item`, skipping empty ones.
+pub fn separated<'a, T>(
+    span: &'a str,
+    separator: char,
+    item: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    span.split(separator)
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(item)
+        .collect()
+}
+
+/// One row of cells per non-blank line, split by `split_row`. Every row must
+/// have as many cells as the first.
+pub fn table<'a>(
+    input: &'a str,
+    mut split_row: impl FnMut(&'a str) -> Result<Vec<&'a str>, ParseError>,
+) -> Result<Vec<Vec<&'a str>>, ParseError> {
+    let mut rows: Vec<Vec<&str>> = Vec::new();
+    for line in non_blank_lines(input) {
+        let row = split_row(line)?;
+        if let Some(width) = rows
+            .first()
+            .map(Vec::len)
+            .filter(|&width| width != row.len())
+        {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {width} entries"),
+            ));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// A [`table`] whose cells are separated by runs of whitespace.
+pub fn whitespace_table(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    table(input, |line| Ok(line.split_whitespace().collect()))
+}
+
+/// A [`table`] whose columns start at the given byte offsets and are separated
+/// by a single space, so each cell keeps its padding. Rows may stop short of
+/// the last column; anything before the first column must be blank.
+pub fn fixed_width_table<'a>(
+    input: &'a str,
+    column_starts: &[usize],
+) -> Result<Vec<Vec<&'a str>>, ParseError> {
+    table(input, |row| {
+        let misaligned = || ParseError::at(input, row, "a row aligned with the columns");
+        let first = column_starts.first().copied().unwrap_or_default();
+        let lead = row.get(..first.min(row.len())).ok_or_else(misaligned)?;
+        if !lead.trim().is_empty() {
+            return Err(misaligned());
+        }
+        column_starts
+            .iter()
+            .enumerate()
+            .map(|(index, &start)| {
+                let end = column_starts
+                    .get(index + 1)
+                    .map_or(row.len(), |&next| next.saturating_sub(1))
+                    .min(row.len());
+                // The character between two columns must be blank.
+                let separator = row.get(end..).and_then(|rest| rest.chars().next());
+                if index + 1 < column_starts.len() && separator.is_some_and(|c| !c.is_whitespace())
+                {
+                    return Err(misaligned());
+                }
+                row.get(start.min(end)..end).ok_or_else(misaligned)
+            })
+            .collect()
+    })
+}
+
+/// A grid of characters, each of which must be one of `allowed`.
+pub fn grid(input: &str, allowed: &str) -> Result<Grid<char>, ParseError> {
+    let choices: Vec<String> = allowed.chars().map(|c| format!("`{c}`")).collect();
+    let expected = match choices.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+        _ => choices.concat(),
+    };
+    Grid::parse(input, |c| allowed.contains(c).then_some(c), &expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_split_on_blank_lines() {
+        let input = "3-5\n10-14\n\n  \n1\n5\n\n";
+        assert_eq!(sections(input), ["3-5\n10-14", "1\n5"]);
+        assert_eq!(sections("\r\na\r\n\r\nb"), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_split_sections_keeps_extra_sections_in_the_last() {
+        let [head, rest] = split_sections::<2>("a\n\nb\n\nc\n", "a blank line").unwrap();
+        assert_eq!((head, rest), ("a", "b\n\nc"));
+
+        let err = split_sections::<2>("a\nb\n", "a blank line").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a blank line"));
+    }
+
+    #[test]
+    fn test_range_reports_each_problem() {
+        let input = "3-5,x-7,9,8-2";
+        let ranges: Vec<Result<RangeInclusive<u64>, ParseError>> = input
+            .split(',')
+            .map(|span| range(input, span, "a range like `3-5`", "an ID"))
+            .collect();
+        assert_eq!(ranges[0], Ok(3..=5));
+        assert_eq!(ranges[1].as_ref().unwrap_err().expected, "an ID");
+        assert_eq!(ranges[2].as_ref().unwrap_err().column, 9);
+        assert_eq!(
+            ranges[3].as_ref().unwrap_err().expected,
+            "a range that ends after it starts"
+        );
+    }
+
+    #[test]
+    fn test_separated_skips_empty_items() {
+        let input = "1, 2,,3,";
+        let numbers = separated(input, ',', |n| number::<u8>(input, n, "a number"));
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_whitespace_table_rejects_ragged_rows() {
+        assert_eq!(
+            whitespace_table("1  2\n 3 4\n"),
+            Ok(vec![vec!["1", "2"], vec!["3", "4"]])
+        );
+        let err = whitespace_table("1 2\n3\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a row of 2 entries"));
+    }
+
+    #[test]
+    fn test_fixed_width_table_keeps_padding() {
+        let input = "123 328\n 45 64 \n*   +  ";
+        assert_eq!(
+            fixed_width_table(input, &[0, 4]),
+            Ok(vec![
+                vec!["123", "328"],
+                vec![" 45", "64 "],
+                vec!["*  ", "+  "]
+            ])
+        );
+        assert!(fixed_width_table("x 1 2", &[2, 4]).is_err());
+        assert!(fixed_width_table("1234 5\n*   +", &[0, 4]).is_err());
+    }
+
+    #[test]
+    fn test_grid_lists_allowed_characters() {
+        assert_eq!(
+            grid("..\n.#", ".^S").unwrap_err().expected,
+            "`.`, `^` or `S`"
+        );
+        assert_eq!(grid("#", "@.").unwrap_err().expected, "`@` or `.`");
+        assert_eq!(grid("#", "@").unwrap_err().expected, "`@`");
+        assert_eq!(grid(".@\n@.", "@.").map(|grid| grid.width()), Ok(2));
+    }
+}
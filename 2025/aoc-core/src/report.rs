@@ -0,0 +1,150 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{AllocStats, Answer, ParseError, Part, Puzzle, track};
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Only counted with the `count-allocations` feature.
+    pub allocations: Option<AllocStats>,
+}
+
+/// Answers and timings for one run of a day, printed as the standard result block.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parse_time: Duration,
+    pub parse_allocations: Option<AllocStats>,
+    pub parts: Vec<PartResult>,
+}
+
+pub fn run(puzzle: &dyn Puzzle, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let (parsed, parse_allocations) = track(|| puzzle.parse(input));
+    let parse_time = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, allocations) = track(|| puzzle.solve(parsed.as_ref(), part));
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+                allocations,
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        title: puzzle.title(),
+        parse_time,
+        parse_allocations,
+        parts,
+    })
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {}: {} (Advent of Code {})",
+            self.day, self.title, self.year
+        )?;
+        writeln!(f, "=========================")?;
+        write!(f, "Parse: {:?}", self.parse_time)?;
+        if let Some(allocations) = self.parse_allocations {
+            write!(f, " ({allocations})")?;
+        }
+        for result in &self.parts {
+            write!(f, "\nPart {}: {}", result.part, result.answer)?;
+            if result.answer.is_solved() {
+                match result.allocations {
+                    Some(allocations) => write!(f, " ({:?}, {allocations})", result.elapsed)?,
+                    None => write!(f, " ({:?})", result.elapsed)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_skips_timing_for_unsolved_parts() {
+        let report = Report {
+            year: 2025,
+            day: 5,
+            title: "Cafeteria",
+            parse_time: Duration::from_micros(5),
+            parse_allocations: None,
+            parts: vec![
+                PartResult {
+                    part: Part::One,
+                    answer: Answer::from(3u64),
+                    elapsed: Duration::from_micros(7),
+                    allocations: None,
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: Answer::Unsolved,
+                    elapsed: Duration::ZERO,
+                    allocations: None,
+                },
+            ],
+        };
+        assert_eq!(
+            report.to_string(),
+            "Day 5: Cafeteria (Advent of Code 2025)\n\
+             =========================\n\
+             Parse: 5µs\n\
+             Part 1: 3 (7µs)\n\
+             Part 2: not implemented"
+        );
+    }
+
+    #[test]
+    fn test_display_adds_allocations_when_counted() {
+        let allocations = AllocStats {
+            allocations: 2,
+            bytes: 2048,
+            peak: 1024,
+        };
+        let report = Report {
+            year: 2025,
+            day: 5,
+            title: "Cafeteria",
+            parse_time: Duration::from_micros(5),
+            parse_allocations: Some(allocations),
+            parts: vec![PartResult {
+                part: Part::One,
+                answer: Answer::from(3u64),
+                elapsed: Duration::from_micros(7),
+                allocations: Some(allocations),
+            }],
+        };
+        let lines: Vec<String> = report.to_string().lines().map(String::from).collect();
+        assert_eq!(
+            lines[2],
+            "Parse: 5µs (2 allocations, 2.00 KiB allocated, 1.00 KiB peak)"
+        );
+        assert_eq!(
+            lines[3],
+            "Part 1: 3 (7µs, 2 allocations, 2.00 KiB allocated, 1.00 KiB peak)"
+        );
+    }
+}
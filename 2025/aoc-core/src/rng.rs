@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded random number generator (SplitMix64) for synthetic inputs.
+/// The same seed always gives the same sequence, on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(
+            start <= end,
+            "cannot pick from the empty range {start}..={end}"
+        );
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    /// A value below `n`, which must be positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick a value below 0");
+        // Multiply-shift keeps the bias negligible for the sizes used here.
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let draws = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(7), draws(7));
+        assert_ne!(draws(7), draws(8));
+    }
+
+    #[test]
+    fn test_values_stay_in_range() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((10..=12).contains(&rng.range(10..=12)));
+            assert!(rng.below(3) < 3);
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(0..=u64::MAX);
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{Answer, ParseError, Rng, Unsolved};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("invalid part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number())
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle: how to parse the raw input once, and how to answer
+/// each part from the parsed model.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input: 'static;
+    type PartOne: Into<Answer> + 'static;
+    type PartTwo: Into<Answer> + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// A valid synthetic input with roughly `size` entries (lines, ranges,
+    /// grid rows, ...), drawn from `rng`; `None` for days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so tooling can keep every day in one list.
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer;
+    /// Whether `part` has a solution at all, rather than answering [`Unsolved`],
+    /// known without an input.
+    fn is_implemented(&self, part: Part) -> bool;
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+}
+
+pub struct Day<S>(PhantomData<fn() -> S>);
+
+impl<S> Day<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Day<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Puzzle for Day<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("Parsed input belongs to a different puzzle!");
+        match part {
+            Part::One => S::part_one(input).into(),
+            Part::Two => S::part_two(input).into(),
+        }
+    }
+
+    fn is_implemented(&self, part: Part) -> bool {
+        let answer = match part {
+            Part::One => TypeId::of::<S::PartOne>(),
+            Part::Two => TypeId::of::<S::PartTwo>(),
+        };
+        answer != TypeId::of::<Unsolved>()
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sum";
+
+        type Input = Vec<u64>;
+        type PartOne = u64;
+        type PartTwo = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| ParseError::at(input, n, "a number")))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> u64 {
+            input.iter().sum()
+        }
+
+        fn part_two(_input: &Self::Input) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_puzzle_dispatches_to_solution() {
+        let puzzle: &dyn Puzzle = &Day::<Sum>::new();
+        let parsed = puzzle.parse("1 2 3").unwrap();
+        assert_eq!(puzzle.solve(parsed.as_ref(), Part::One), Answer::from(6u64));
+        assert_eq!(puzzle.solve(parsed.as_ref(), Part::Two), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_puzzle_knows_which_parts_are_implemented() {
+        let puzzle: &dyn Puzzle = &Day::<Sum>::new();
+        assert!(puzzle.is_implemented(Part::One));
+        assert!(!puzzle.is_implemented(Part::Two));
+    }
+
+    #[test]
+    fn test_puzzle_surfaces_parse_errors() {
+        let puzzle: &dyn Puzzle = &Day::<Sum>::new();
+        let err = puzzle.parse("1 x 3").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+}
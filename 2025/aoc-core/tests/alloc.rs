@@ -0,0 +1,18 @@
+//! The allocation counters are process-wide, so this check lives in its own
+//! test binary where nothing else allocates alongside it.
+#![cfg(feature = "count-allocations")]
+
+use aoc_core::track;
+
+#[test]
+fn test_track_counts_allocations_of_the_step() {
+    let (_, stats) = track(|| {
+        let big = vec![0u8; 4096];
+        drop(big);
+        vec![0u8; 1024]
+    });
+    let stats = stats.unwrap();
+    assert!(stats.allocations >= 2);
+    assert!(stats.bytes >= 5120);
+    assert!(stats.peak >= 4096);
+}
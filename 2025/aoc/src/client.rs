@@ -0,0 +1,261 @@
+use std::{error::Error, fs, path::Path, time::Duration};
+
+use ureq::Agent;
+
+use aoc_core::Part;
+
+use crate::{config::Config, submit::Response};
+
+/// Identifies the tool to the site, as its automation guidelines ask.
+const USER_AGENT: &str = concat!(
+    "github.com/mebyn/advent-of-code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Talks to the site on behalf of the session from a [`Config`].
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+        Self {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session: config.session.trim().to_string(),
+        }
+    }
+
+    /// Returns straight away if `path` already holds a real input or an
+    /// encrypted copy of one, otherwise downloads it there. A cached input is
+    /// never requested again.
+    pub fn fetch(&self, year: u16, day: u8, path: &Path) -> Result<Fetched, Box<dyn Error>> {
+        if is_cached(path) {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.download(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write to a sibling first so an interrupted write never looks cached.
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, path)?;
+        Ok(Fetched::Downloaded)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call()
+            .map_err(|err| format!("failed to reach {url}: {err}"))?;
+        let input = read_body(&url, year, day, response)?;
+        if aoc_core::check(&input).is_some() {
+            return Err(format!("{url} returned an empty input").into());
+        }
+        Ok(input)
+    }
+
+    /// Posts `answer` for one part. Checking it against the submission history
+    /// is up to the caller.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Response, Box<dyn Error>> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|err| format!("failed to reach {url}: {err}"))?;
+        Ok(Response::parse(&read_body(&url, year, day, response)?))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_body(
+    url: &str,
+    year: u16,
+    day: u8,
+    mut response: ureq::http::Response<ureq::Body>,
+) -> Result<String, Box<dyn Error>> {
+    let status = response.status().as_u16();
+    match status {
+        200 => Ok(response.body_mut().read_to_string()?),
+        400 | 500 => {
+            Err(format!("{url} rejected the session token ({status}), it may have expired").into())
+        }
+        404 => Err(format!("{year} day {day} is not unlocked yet").into()),
+        _ => Err(format!("{url} answered with status {status}").into()),
+    }
+}
+
+/// Whether `path` holds an input worth keeping rather than nothing or the
+/// placeholder, or has an encrypted copy next to it.
+pub fn is_cached(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|input| aoc_core::check(&input).is_none())
+        || aoc_core::encrypted_path(path).exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    struct Request {
+        method: String,
+        url: String,
+        body: String,
+        cookie: Option<String>,
+        user_agent: Option<String>,
+    }
+
+    /// Serves `status`/`body` to every request on a local port, recording what it was sent.
+    fn serve(status: u16, body: &'static str) -> (Client, Arc<Mutex<Vec<Request>>>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut sent = String::new();
+                request.as_reader().read_to_string(&mut sent).unwrap();
+                let header = |name: &str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.as_str().as_str().eq_ignore_ascii_case(name))
+                        .map(|header| header.value.to_string())
+                };
+                recorded.lock().unwrap().push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    body: sent,
+                    cookie: header("Cookie"),
+                    user_agent: header("User-Agent"),
+                });
+                let response = Response::from_string(body).with_status_code(status);
+                let _ = request.respond(response);
+            }
+        });
+        let config = Config {
+            session: "secret".to_string(),
+            base_url,
+        };
+        (Client::new(&config), requests)
+    }
+
+    #[test]
+    fn test_downloads_with_session_and_user_agent() {
+        let (client, requests) = serve(200, "3-5\n\n1\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("2025/day05.txt");
+
+        assert_eq!(client.fetch(2025, 5, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3-5\n\n1\n");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2025/day/5/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+        assert_eq!(requests[0].user_agent.as_deref(), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn test_never_downloads_a_cached_input() {
+        let (client, requests) = serve(200, "fresh\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, "cached\n").unwrap();
+
+        assert_eq!(client.fetch(2025, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "cached\n");
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_never_downloads_an_encrypted_input() {
+        let (client, requests) = serve(200, "fresh\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, format!("{}\n", aoc_core::PLACEHOLDER)).unwrap();
+        fs::write(aoc_core::encrypted_path(&path), "sealed").unwrap();
+
+        assert_eq!(client.fetch(2025, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("{}\n", aoc_core::PLACEHOLDER)
+        );
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_replaces_placeholder_input() {
+        let (client, _) = serve(200, "fresh\n");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, format!("{}\n", aoc_core::PLACEHOLDER)).unwrap();
+
+        assert_eq!(client.fetch(2025, 8, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fresh\n");
+    }
+
+    #[test]
+    fn test_rejected_session_caches_nothing() {
+        let (client, _) = serve(400, "Puzzle inputs differ by user.  Please log in.");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+
+        let err = client.fetch(2025, 1, &path).unwrap_err();
+        assert!(err.to_string().contains("rejected the session token"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_submit_posts_level_and_answer() {
+        let (client, requests) = serve(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let response = client.submit(2025, 5, Part::Two, "14").unwrap();
+        assert_eq!(response.outcome, crate::submit::Outcome::Correct);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2025/day/5/answer");
+        assert_eq!(requests[0].body, "level=2&answer=14");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::config_dir;
+use serde::Deserialize;
+
+/// Overrides where the config file is read from.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code website, read from TOML:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com" # optional
+/// ```
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie of a logged-in browser.
+    pub session: String,
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+}
+
+fn default_base_url() -> String {
+    DEFAULT_BASE_URL.to_string()
+}
+
+impl Config {
+    /// [`CONFIG_VAR`] if set, otherwise `aoc/config.toml` in the user's config directory.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        Some(config_dir()?.join("config.toml"))
+    }
+
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let path = Self::path()
+            .ok_or_else(|| format!("cannot locate a config file, set {CONFIG_VAR} to its path"))?;
+        Self::load_from(&path)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read config {}: {err}", path.display()))?;
+        let config: Self = toml::from_str(&contents)
+            .map_err(|err| format!("invalid config {}: {err}", path.display()))?;
+        if config.session.trim().is_empty() {
+            return Err(format!("no session token in {}", path.display()).into());
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url_defaults_to_website() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "session = \"abc\"\n").unwrap();
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.session, "abc");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn test_rejects_blank_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "session = \"\"\n").unwrap();
+        assert!(Config::load_from(&path).is_err());
+    }
+}
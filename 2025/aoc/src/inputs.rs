@@ -0,0 +1,180 @@
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{CryptError, InputKey, InputSource, Puzzle, check, encrypted_path};
+
+use crate::{day_dir, puzzles, workspace_dir};
+
+/// What encrypting or decrypting one day's input did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Written,
+    /// The target already holds the same input.
+    Unchanged,
+    /// There is no real input to read.
+    Missing,
+    /// The plaintext input differs from the encrypted copy and was left alone.
+    Conflict,
+}
+
+/// Encrypts the real input of every matching day to `input.txt.enc`,
+/// creating the key first if there is none yet.
+pub fn encrypt(year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let key_path = key_path()?;
+    if !key_path.exists() {
+        InputKey::generate()?.save(&key_path)?;
+        println!(
+            "Created a new input key at {}; keep a copy somewhere safe, the encrypted \
+             inputs cannot be read without it",
+            key_path.display()
+        );
+    }
+    let key = InputKey::load(&key_path)?;
+    for puzzle in selected(year, day) {
+        let path = input_path(puzzle);
+        match encrypt_input(&key, &path)? {
+            Outcome::Written => println!("Encrypted {}", shown(&encrypted_path(&path))),
+            Outcome::Unchanged => println!("{} is up to date", shown(&encrypted_path(&path))),
+            Outcome::Missing | Outcome::Conflict => {}
+        }
+    }
+    Ok(())
+}
+
+/// Restores `input.txt` from `input.txt.enc` for every matching day, never
+/// overwriting a different real input.
+pub fn decrypt(year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let key = InputKey::load(&key_path()?)?;
+    let mut conflicts = 0;
+    for puzzle in selected(year, day) {
+        let path = input_path(puzzle);
+        match decrypt_input(&key, &path)? {
+            Outcome::Written => println!("Decrypted {}", shown(&path)),
+            Outcome::Unchanged => println!("{} is up to date", shown(&path)),
+            Outcome::Conflict => {
+                conflicts += 1;
+                eprintln!(
+                    "{} differs from {}, left alone",
+                    shown(&path),
+                    shown(&encrypted_path(&path))
+                );
+            }
+            Outcome::Missing => {}
+        }
+    }
+    match conflicts {
+        0 => Ok(()),
+        _ => Err(format!("{conflicts} input(s) differ from their encrypted copy").into()),
+    }
+}
+
+fn encrypt_input(key: &InputKey, path: &Path) -> Result<Outcome, Box<dyn Error>> {
+    let Some(input) = read_real(path)? else {
+        return Ok(Outcome::Missing);
+    };
+    let encrypted = encrypted_path(path);
+    // Encrypting uses a fresh nonce, so rewriting an unchanged input would
+    // still produce a new file to commit.
+    if let Ok(existing) = fs::read(&encrypted)
+        && key
+            .decrypt(&existing)
+            .is_ok_and(|existing| existing == input)
+    {
+        return Ok(Outcome::Unchanged);
+    }
+    fs::write(&encrypted, key.encrypt(&input)?)?;
+    Ok(Outcome::Written)
+}
+
+fn decrypt_input(key: &InputKey, path: &Path) -> Result<Outcome, Box<dyn Error>> {
+    let encrypted = match fs::read(encrypted_path(path)) {
+        Ok(encrypted) => encrypted,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Outcome::Missing),
+        Err(err) => return Err(err.into()),
+    };
+    let input = key
+        .decrypt(&encrypted)
+        .map_err(|err| format!("failed to decrypt {}: {err}", shown(&encrypted_path(path))))?;
+    match read_real(path)? {
+        Some(existing) if existing == input => Ok(Outcome::Unchanged),
+        Some(_) => Ok(Outcome::Conflict),
+        None => {
+            fs::write(path, input)?;
+            Ok(Outcome::Written)
+        }
+    }
+}
+
+/// The input at `path`, unless it is missing, empty or the template placeholder.
+fn read_real(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(input) if check(&input).is_none() => Ok(Some(input)),
+        Ok(_) => Ok(None),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn key_path() -> Result<PathBuf, CryptError> {
+    InputKey::default_path().ok_or(CryptError::NoKeyPath)
+}
+
+fn selected(year: Option<u16>, day: Option<u8>) -> impl Iterator<Item = &'static dyn Puzzle> {
+    puzzles::PUZZLES.iter().copied().filter(move |puzzle| {
+        year.is_none_or(|year| year == puzzle.year()) && day.is_none_or(|day| day == puzzle.day())
+    })
+}
+
+fn input_path(puzzle: &dyn Puzzle) -> PathBuf {
+    InputSource::default_path(puzzle.year(), puzzle.day(), &day_dir(puzzle.day()))
+}
+
+/// `path` relative to the workspace when it is inside it.
+fn shown(path: &Path) -> String {
+    path.strip_prefix(workspace_dir())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::PLACEHOLDER;
+
+    use super::*;
+
+    #[test]
+    fn test_encrypt_then_decrypt_restores_the_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        let key = InputKey::generate().unwrap();
+        assert_eq!(encrypt_input(&key, &path).unwrap(), Outcome::Missing);
+
+        fs::write(&path, "L68\nR48\n").unwrap();
+        assert_eq!(encrypt_input(&key, &path).unwrap(), Outcome::Written);
+        let encrypted = fs::read(encrypted_path(&path)).unwrap();
+        assert_eq!(encrypt_input(&key, &path).unwrap(), Outcome::Unchanged);
+        assert_eq!(fs::read(encrypted_path(&path)).unwrap(), encrypted);
+
+        fs::write(&path, PLACEHOLDER).unwrap();
+        assert_eq!(decrypt_input(&key, &path).unwrap(), Outcome::Written);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "L68\nR48\n");
+        assert_eq!(decrypt_input(&key, &path).unwrap(), Outcome::Unchanged);
+    }
+
+    #[test]
+    fn test_decrypt_leaves_a_different_input_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        let key = InputKey::generate().unwrap();
+        fs::write(&path, "L68\n").unwrap();
+        encrypt_input(&key, &path).unwrap();
+
+        fs::write(&path, "R48\n").unwrap();
+        assert_eq!(decrypt_input(&key, &path).unwrap(), Outcome::Conflict);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "R48\n");
+    }
+}
@@ -0,0 +1,327 @@
+use std::{
+    env,
+    error::Error,
+    fmt,
+    io::{self, Read, Write},
+    panic,
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, Day, ParseError, Part, Puzzle, Solution, Unsolved};
+use serde::{Deserialize, Serialize};
+
+/// Resource limits for a part run in its own process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Duration,
+    /// Address space limit in bytes; not enforced outside Unix.
+    pub memory: u64,
+}
+
+/// What the child process reports on stdout, as a single JSON line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum ChildReport {
+    Solved { answer: Option<String>, nanos: u64 },
+    ParseError(String),
+    Panicked(String),
+}
+
+/// Why an isolated part produced no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    TimedOut(Duration),
+    Panicked(String),
+    /// Killed by a signal or exited abnormally, e.g. a stack overflow or
+    /// running out of memory; holds the last thing it wrote to stderr.
+    Crashed(String),
+    ParseError(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TimedOut(after) => write!(f, "timed out after {after:?}"),
+            Self::Panicked(message) => write!(f, "panicked with {message}"),
+            Self::Crashed(reason) => write!(f, "crashed ({reason})"),
+            Self::ParseError(err) => write!(f, "failed to parse input: {err}"),
+        }
+    }
+}
+
+/// Year of the [`FIXTURES`], long before the first Advent of Code.
+pub const FIXTURE_YEAR: u16 = 1;
+
+/// Hidden puzzles for testing isolation end to end, run with
+/// `aoc run-all 1 --isolate`. Part one answers with its input, except that
+/// `panic` panics and `hang` never returns.
+pub static FIXTURES: &[&dyn Puzzle] = &[
+    &Day::<Fixture<1>>::new(),
+    &Day::<Fixture<2>>::new(),
+    &Day::<Fixture<3>>::new(),
+];
+
+/// The fixture registered for `year`/`day`, if any.
+pub fn fixture(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    FIXTURES
+        .iter()
+        .copied()
+        .find(|puzzle| puzzle.year() == year && puzzle.day() == day)
+}
+
+struct Fixture<const DAY: u8>;
+
+impl<const DAY: u8> Solution for Fixture<DAY> {
+    const YEAR: u16 = FIXTURE_YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Isolation fixture";
+
+    type Input = String;
+    type PartOne = String;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> String {
+        match input.as_str() {
+            "panic" => panic!("Fixture panics when asked to!"),
+            "hang" => loop {
+                thread::sleep(Duration::from_secs(1));
+            },
+            answer => answer.to_string(),
+        }
+    }
+
+    fn part_two(_input: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+/// Solves `part` of the registered `year`/`day` in a child copy of this
+/// executable, feeding it `input` on stdin and enforcing `limits`.
+pub fn solve(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &str,
+    limits: Limits,
+) -> Result<Result<(Answer, Duration), Failure>, Box<dyn Error>> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args(["solve-isolated", &year.to_string(), &day.to_string()])
+        .arg(part.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    limit_memory(&mut command, limits.memory);
+    let mut child = command.spawn()?;
+
+    // A child that dies before reading all of its input closes the pipe;
+    // its exit status says why, so the write error itself is not interesting.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let status = wait(&mut child, limits.timeout)?;
+    let _ = writer.join();
+    let Some(status) = status else {
+        return Ok(Err(Failure::TimedOut(limits.timeout)));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let report = stdout
+        .lines()
+        .last()
+        .and_then(|line| serde_json::from_str(line).ok());
+    Ok(match report {
+        Some(ChildReport::Solved { answer, nanos }) => Ok((
+            answer.map_or(Answer::Unsolved, Answer::Solved),
+            Duration::from_nanos(nanos),
+        )),
+        Some(ChildReport::ParseError(err)) => Err(Failure::ParseError(err)),
+        Some(ChildReport::Panicked(message)) => Err(Failure::Panicked(message)),
+        None => Err(Failure::Crashed(crash_reason(status, &stderr))),
+    })
+}
+
+/// Runs inside the child: solves one part of `puzzle` from stdin and prints a [`ChildReport`].
+pub fn solve_in_child(puzzle: &dyn Puzzle, part: Part) -> Result<(), Box<dyn Error>> {
+    panic::set_hook(Box::new(|info| {
+        let message = info.payload_as_str().unwrap_or("a non-string payload");
+        let location = info
+            .location()
+            .map(|location| format!(" at {location}"))
+            .unwrap_or_default();
+        report(&ChildReport::Panicked(format!("`{message}`{location}")));
+    }));
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            report(&ChildReport::ParseError(err.to_string()));
+            return Ok(());
+        }
+    };
+    let start = Instant::now();
+    let answer = puzzle.solve(parsed.as_ref(), part);
+    let nanos = start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX);
+    report(&ChildReport::Solved {
+        answer: answer.is_solved().then(|| answer.to_string()),
+        nanos,
+    });
+    Ok(())
+}
+
+fn report(report: &ChildReport) {
+    let json = serde_json::to_string(report).expect("Child reports always serialize");
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{json}");
+    let _ = stdout.flush();
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Waits for `child` to exit, killing it once `timeout` has passed.
+fn wait(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn crash_reason(status: ExitStatus, stderr: &str) -> String {
+    let last_line = stderr
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .map(str::trim);
+    let status = describe(status);
+    match last_line {
+        Some(line) => format!("{status}: {line}"),
+        None => status,
+    }
+}
+
+#[cfg(unix)]
+fn describe(status: ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+
+    match status.signal() {
+        Some(libc::SIGSEGV) => "segmentation fault".to_string(),
+        Some(libc::SIGABRT) => "aborted".to_string(),
+        Some(libc::SIGKILL) => "killed".to_string(),
+        Some(signal) => format!("killed by signal {signal}"),
+        None => status.to_string(),
+    }
+}
+
+#[cfg(not(unix))]
+fn describe(status: ExitStatus) -> String {
+    status.to_string()
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+    // SAFETY: setrlimit is async-signal-safe and only touches the forked child.
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _bytes: u64) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_child_reports_round_trip_through_json() {
+        let reports = [
+            ChildReport::Solved {
+                answer: Some("733".to_string()),
+                nanos: 5,
+            },
+            ChildReport::Solved {
+                answer: None,
+                nanos: 0,
+            },
+            ChildReport::Panicked("`boom` at src/lib.rs:1:1".to_string()),
+        ];
+        for report in reports {
+            let json = serde_json::to_string(&report).unwrap();
+            assert_eq!(serde_json::from_str::<ChildReport>(&json).unwrap(), report);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_kills_children_that_overrun() {
+        let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+        let start = Instant::now();
+        assert_eq!(wait(&mut child, Duration::from_millis(50)).unwrap(), None);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_crash_reason_uses_the_last_stderr_line() {
+        let status = Command::new("sh").args(["-c", "exit 3"]).status().unwrap();
+        assert_eq!(
+            crash_reason(
+                status,
+                "note: something\nmemory allocation of 8 bytes failed\n"
+            ),
+            "exit status: 3: memory allocation of 8 bytes failed"
+        );
+    }
+
+    #[test]
+    fn test_failures_read_as_a_sentence() {
+        assert_eq!(
+            Failure::TimedOut(Duration::from_secs(2)).to_string(),
+            "timed out after 2s"
+        );
+        assert_eq!(
+            Failure::Panicked("`boom`".to_string()).to_string(),
+            "panicked with `boom`"
+        );
+    }
+}
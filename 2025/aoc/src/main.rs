@@ -0,0 +1,304 @@
+mod client;
+mod config;
+mod inputs;
+mod isolate;
+mod puzzles;
+mod readme;
+mod run_all;
+mod scaffold;
+mod submit;
+mod verify;
+mod watch;
+
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{Answer, Answers, BenchOptions, InputSource, Part, Puzzle, cli::RunArgs};
+use clap::{Parser, Subcommand};
+use client::{Client, Fetched};
+use config::Config;
+use isolate::Limits;
+use submit::{History, Outcome};
+
+/// The Advent of Code year this workspace holds.
+const YEAR: u16 = 2025;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution and print its answers
+    Run {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+    /// Run every implemented day and check the answers against answers.toml
+    RunAll {
+        #[arg(default_value_t = YEAR)]
+        year: u16,
+        /// Run the days on separate threads; timings become less reliable
+        #[arg(long, short)]
+        parallel: bool,
+        /// Run each part in its own process, reporting timeouts, crashes and
+        /// panics instead of stopping
+        #[arg(long)]
+        isolate: bool,
+        /// Seconds an isolated part may run before it is killed
+        #[arg(long, default_value_t = 30, requires = "isolate")]
+        timeout: u64,
+        /// Memory an isolated part may use, in MiB
+        #[arg(long, default_value_t = 2048, requires = "isolate")]
+        memory: u64,
+    },
+    /// Time parsing and each part over many iterations
+    Bench {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        args: RunArgs,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = BenchOptions::default().warmup)]
+        warmup: usize,
+        /// Timed runs per step
+        #[arg(long, default_value_t = BenchOptions::default().iterations)]
+        iterations: usize,
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a new day crate from the template and register it
+    New {
+        year: u16,
+        day: u8,
+        /// Puzzle title shown in run output
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Download a day's input into the cache unless it is already there
+    Fetch { year: u16, day: u8 },
+    /// Submit an answer, computing it from the day's input when not given
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+    },
+    /// Print a synthetic input for stress tests and benchmarks
+    Generate {
+        year: u16,
+        day: u8,
+        /// Roughly how many entries (lines, ranges, grid rows) to generate
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// The same seed always produces the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Run and time every day and rewrite the progress table in README.md
+    Readme {
+        #[arg(default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Re-run a day's tests and solution whenever its sources or input change
+    Watch { year: u16, day: u8 },
+    /// Re-run solved days against their real input and compare with answers.toml
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Encrypt each day's input to input.txt.enc, creating the input key if needed
+    Encrypt { year: Option<u16>, day: Option<u8> },
+    /// Restore each day's input.txt from its encrypted copy
+    Decrypt { year: Option<u16>, day: Option<u8> },
+    /// Solve one part of the input on stdin; the child side of `run-all --isolate`
+    #[command(hide = true)]
+    SolveIsolated { year: u16, day: u8, part: Part },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run { year, day, args } => {
+            let puzzle = find_puzzle(year, day)?;
+            let input = args.input_source(year, day, &day_dir(day)).read(day)?;
+            println!("{}", aoc_core::run(puzzle, &input, &args.parts())?);
+            Ok(())
+        }
+        Command::RunAll {
+            year,
+            parallel,
+            isolate,
+            timeout,
+            memory,
+        } => {
+            let answers = Answers::load(&answers_path())?;
+            let limits = isolate.then_some(Limits {
+                timeout: Duration::from_secs(timeout),
+                memory: memory * 1024 * 1024,
+            });
+            run_all::run_all(&answers, year, parallel, limits)
+        }
+        Command::Bench {
+            year,
+            day,
+            args,
+            warmup,
+            iterations,
+            json,
+        } => {
+            let puzzle = find_puzzle(year, day)?;
+            let input = args.input_source(year, day, &day_dir(day)).read(day)?;
+            let options = BenchOptions { warmup, iterations };
+            let benchmark = aoc_core::bench(puzzle, &input, &args.parts(), options)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&benchmark)?);
+            } else {
+                println!("{benchmark}");
+            }
+            Ok(())
+        }
+        Command::New { year, day, title } => {
+            if year != YEAR {
+                return Err(format!("this workspace holds {YEAR}, not {year}").into());
+            }
+            let title = title.unwrap_or_else(|| format!("Day {day}"));
+            scaffold::new_day(workspace_dir(), year, day, &title)?;
+            println!("Created {}", day_dir(day).display());
+            Ok(())
+        }
+        Command::Fetch { year, day } => {
+            let path = InputSource::default_path(year, day, &day_dir(day));
+            let client = Client::new(&Config::load()?);
+            match client.fetch(year, day, &path)? {
+                Fetched::Cached => println!("{} is already cached", path.display()),
+                Fetched::Downloaded => println!("Downloaded {}", path.display()),
+            }
+            Ok(())
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => solve(year, day, part)?,
+            };
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            let path = workspace_dir().join("submissions.toml");
+            let mut history = History::load(&path)?;
+            if let Some(reason) = history.refusal(year, day, part, &answer, now) {
+                return Err(format!("not submitting {answer}: {reason}").into());
+            }
+            let client = Client::new(&Config::load()?);
+            let response = client.submit(year, day, part, &answer)?;
+            history.record(year, day, part, &answer, response, now);
+            history.save(&path)?;
+            match response.outcome {
+                Outcome::Correct => {
+                    let mut answers = Answers::load(&answers_path())?;
+                    answers.record(&answers_path(), year, day, part, &answer)?;
+                    println!("{answer} is right, recorded in answers.toml");
+                }
+                Outcome::Wrong => println!("{answer} is wrong"),
+                Outcome::TooHigh => println!("{answer} is wrong, too high"),
+                Outcome::TooLow => println!("{answer} is wrong, too low"),
+                Outcome::TooSoon => println!("Submitted too soon, {answer} was not checked"),
+                Outcome::WrongLevel => println!("Part {part} is already solved or still locked"),
+                Outcome::Unrecognised => println!("Could not understand the site's response"),
+            }
+            if let Some(wait) = response.wait {
+                println!("Wait {wait}s before submitting again");
+            }
+            Ok(())
+        }
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let input = find_puzzle(year, day)?
+                .generate(seed, size)
+                .ok_or_else(|| format!("{year} day {day} has no input generator"))?;
+            println!("{input}");
+            Ok(())
+        }
+        Command::Readme { year } => {
+            let answers = Answers::load(&answers_path())?;
+            let path = workspace_dir()
+                .parent()
+                .expect("Workspace lives inside the repository!")
+                .join("README.md");
+            readme::update(&path, &answers, year)?;
+            println!("Updated {}", path.display());
+            Ok(())
+        }
+        Command::Watch { year, day } => {
+            find_puzzle(year, day)?;
+            let input = InputSource::default_path(year, day, &day_dir(day));
+            watch::watch(workspace_dir(), day, &input)
+        }
+        Command::Verify { year, day } => {
+            let answers = Answers::load(&answers_path())?;
+            verify::verify(&answers, year, day)
+        }
+        Command::Encrypt { year, day } => inputs::encrypt(year, day),
+        Command::Decrypt { year, day } => inputs::decrypt(year, day),
+        Command::SolveIsolated { year, day, part } => {
+            let puzzle = match isolate::fixture(year, day) {
+                Some(fixture) => fixture,
+                None => find_puzzle(year, day)?,
+            };
+            isolate::solve_in_child(puzzle, part)
+        }
+    }
+}
+
+fn find_puzzle(year: u16, day: u8) -> Result<&'static dyn Puzzle, String> {
+    puzzles::find(year, day).ok_or_else(|| format!("no solution registered for {year} day {day}"))
+}
+
+fn solve(year: u16, day: u8, part: Part) -> Result<String, Box<dyn Error>> {
+    let puzzle = find_puzzle(year, day)?;
+    let input = InputSource::default_path(year, day, &day_dir(day));
+    let input = InputSource::File(input).read(day)?;
+    let report = aoc_core::run(puzzle, &input, &[part])?;
+    match report.parts.into_iter().next().map(|result| result.answer) {
+        Some(Answer::Solved(answer)) => Ok(answer),
+        _ => Err(format!("{year} day {day} part {part} is not solved yet").into()),
+    }
+}
+
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate lives inside the workspace!")
+}
+
+fn answers_path() -> PathBuf {
+    workspace_dir().join("answers.toml")
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{day:02}"))
+}
@@ -0,0 +1,53 @@
+use aoc_core::{Day, Puzzle};
+
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &Day::<day01::Day01>::new(),
+    &Day::<day02::Day02>::new(),
+    &Day::<day03::Day03>::new(),
+    &Day::<day04::Day04>::new(),
+    &Day::<day05::Day05>::new(),
+    &Day::<day06::Day06>::new(),
+    &Day::<day07::Day07>::new(),
+    &Day::<day08::Day08>::new(),
+    &Day::<day09::Day09>::new(),
+    &Day::<day10::Day10>::new(),
+    &Day::<day11::Day11>::new(),
+    &Day::<day12::Day12>::new(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    PUZZLES
+        .iter()
+        .copied()
+        .find(|puzzle| puzzle.year() == year && puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_2025_day_is_registered_once() {
+        for day in 1..=12 {
+            let matches = PUZZLES
+                .iter()
+                .filter(|puzzle| puzzle.year() == 2025 && puzzle.day() == day)
+                .count();
+            assert_eq!(matches, 1, "day {day}");
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_parse_at_every_size() {
+        for puzzle in PUZZLES {
+            for size in [0, 1, 2] {
+                let Some(input) = puzzle.generate(7, size) else {
+                    continue;
+                };
+                if let Err(err) = puzzle.parse(&input) {
+                    panic!("day {} at size {size}: {err}", puzzle.day());
+                }
+            }
+        }
+    }
+}
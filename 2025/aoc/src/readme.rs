@@ -0,0 +1,183 @@
+use std::{error::Error, fmt::Write, fs, path::Path, time::Duration};
+
+use aoc_core::{Answer, Answers, BenchOptions, InputSource, Part, Puzzle, Verdict};
+
+use crate::{day_dir, puzzles};
+
+/// The generated table sits between these markers in `README.md`.
+pub const START: &str = "<!-- progress:start -->";
+pub const END: &str = "<!-- progress:end -->";
+
+/// How one part of a day stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Matches the answer recorded in `answers.toml`.
+    Verified,
+    /// Solved, but there is no recorded answer to compare with.
+    Unverified,
+    /// Differs from the recorded answer.
+    Wrong,
+    Unsolved,
+}
+
+impl Status {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Verified => "⭐",
+            Self::Unverified => "❔",
+            Self::Wrong => "❌",
+            Self::Unsolved => "–",
+        }
+    }
+}
+
+/// A row of the progress table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: [Status; 2],
+    /// Median parse time plus the median of each solved part; `None` when
+    /// there is no input or nothing is solved.
+    pub runtime: Option<Duration>,
+}
+
+/// Runs and times every registered day of `year` and rewrites the progress
+/// section of `readme`.
+pub fn update(readme: &Path, answers: &Answers, year: u16) -> Result<(), Box<dyn Error>> {
+    let mut rows = Vec::new();
+    for &puzzle in puzzles::PUZZLES
+        .iter()
+        .filter(|puzzle| puzzle.year() == year)
+    {
+        rows.push(progress(puzzle, answers)?);
+    }
+    let contents = fs::read_to_string(readme)?;
+    let updated = replace_section(&contents, &table(year, &rows)).ok_or_else(|| {
+        format!(
+            "{} has no {START} ... {END} section to update",
+            readme.display()
+        )
+    })?;
+    fs::write(readme, updated)?;
+    Ok(())
+}
+
+fn progress(puzzle: &dyn Puzzle, answers: &Answers) -> Result<Progress, Box<dyn Error>> {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let recorded = |part| match answers.get(year, day, part) {
+        Some(_) => Status::Verified,
+        None => Status::Unsolved,
+    };
+    let mut row = Progress {
+        day,
+        title: puzzle.title(),
+        parts: Part::ALL.map(recorded),
+        runtime: None,
+    };
+    // Without an input, recorded answers are the best evidence of progress.
+    let Ok(input) = InputSource::resolve(None, year, day, &day_dir(day)).read(day) else {
+        return Ok(row);
+    };
+
+    let report = aoc_core::run(puzzle, &input, &Part::ALL)
+        .map_err(|err| format!("day {day:02} no longer parses its input:\n{err}"))?;
+    let mut solved = Vec::new();
+    for result in &report.parts {
+        row.parts[usize::from(result.part.number() - 1)] = match result.answer {
+            Answer::Unsolved => Status::Unsolved,
+            _ => match answers.verdict(year, day, result.part, &result.answer) {
+                Verdict::Pass => Status::Verified,
+                Verdict::Fail { .. } => Status::Wrong,
+                Verdict::Unknown => Status::Unverified,
+            },
+        };
+        if result.answer.is_solved() {
+            solved.push(result.part);
+        }
+    }
+    if !solved.is_empty() {
+        let options = BenchOptions {
+            warmup: 1,
+            iterations: 10,
+        };
+        let benchmark = aoc_core::bench(puzzle, &input, &solved, options)?;
+        let parts: Duration = benchmark.parts.iter().map(|part| part.stats.median).sum();
+        row.runtime = Some(benchmark.parse.median + parts);
+    }
+    Ok(row)
+}
+
+/// The progress section: a markdown table with one row per day and a legend.
+pub fn table(year: u16, rows: &[Progress]) -> String {
+    let mut out = String::from(
+        "| Day | Title | Part 1 | Part 2 | Runtime |\n\
+         |----:|-------|:------:|:------:|--------:|\n",
+    );
+    for row in rows {
+        let runtime = row
+            .runtime
+            .map_or("–".to_string(), |runtime| format!("{runtime:.2?}"));
+        let _ = writeln!(
+            out,
+            "| [{}]({year}/day{:02}) | {} | {} | {} | {runtime} |",
+            row.day,
+            row.day,
+            row.title,
+            row.parts[0].symbol(),
+            row.parts[1].symbol(),
+        );
+    }
+    out.push_str(
+        "\n⭐ matches `answers.toml` · ❔ solved but not recorded · ❌ differs from \
+         `answers.toml`. Runtime is the median parse time plus each solved part.\n",
+    );
+    out
+}
+
+/// `readme` with everything between the markers replaced by `section`.
+pub fn replace_section(readme: &str, section: &str) -> Option<String> {
+    let start = readme.find(START)? + START.len();
+    let end = start + readme[start..].find(END)?;
+    Some(format!("{}\n{section}{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_lists_each_day() {
+        let rows = [
+            Progress {
+                day: 5,
+                title: "Cafeteria",
+                parts: [Status::Verified, Status::Unverified],
+                runtime: Some(Duration::from_micros(250)),
+            },
+            Progress {
+                day: 12,
+                title: "Day 12",
+                parts: [Status::Wrong, Status::Unsolved],
+                runtime: None,
+            },
+        ];
+        let table = table(2025, &rows);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[2],
+            "| [5](2025/day05) | Cafeteria | ⭐ | ❔ | 250.00µs |"
+        );
+        assert_eq!(lines[3], "| [12](2025/day12) | Day 12 | ❌ | – | – |");
+    }
+
+    #[test]
+    fn test_replace_section_keeps_the_rest_of_the_readme() {
+        let readme = format!("# Title\n\n{START}\nold table\n{END}\n\nMore text\n");
+        assert_eq!(
+            replace_section(&readme, "new table\n").unwrap(),
+            format!("# Title\n\n{START}\nnew table\n{END}\n\nMore text\n")
+        );
+        assert_eq!(replace_section("# Title\n", "new table\n"), None);
+    }
+}
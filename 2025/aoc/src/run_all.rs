@@ -0,0 +1,323 @@
+use std::{error::Error, fmt::Write, thread, time::Duration};
+
+use aoc_core::{Answer, Answers, InputError, InputSource, Part, Puzzle, Verdict};
+
+use crate::{
+    day_dir,
+    isolate::{self, Failure, Limits},
+    puzzles,
+};
+
+/// One part of a day and how running it went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// An answer, checked against `answers.toml`.
+    Answered {
+        answer: String,
+        elapsed: Duration,
+        verdict: Verdict,
+    },
+    /// An isolated part that timed out, crashed or panicked.
+    Failed(String),
+}
+
+/// What running a single day produced.
+#[derive(Debug)]
+enum DayRun {
+    Solved(Vec<Row>),
+    /// A stub with no solved parts, or a day without a real input.
+    Skipped(u8, String),
+    Failed(u8, String),
+}
+
+/// Runs every registered day of `year` that has an input and at least one
+/// solved part, printing a table of the results. Fails if any answer differs
+/// from `answers.toml` or a day could not be run at all.
+///
+/// With `limits`, each part runs in its own process so one that hangs or
+/// crashes is reported in the table while the others still run.
+pub fn run_all(
+    answers: &Answers,
+    year: u16,
+    parallel: bool,
+    limits: Option<Limits>,
+) -> Result<(), Box<dyn Error>> {
+    // The fixtures misbehave on purpose, so they only run isolated.
+    let fixtures = if limits.is_some() {
+        isolate::FIXTURES
+    } else {
+        &[]
+    };
+    let days: Vec<&dyn Puzzle> = puzzles::PUZZLES
+        .iter()
+        .chain(fixtures)
+        .copied()
+        .filter(|puzzle| puzzle.year() == year)
+        .collect();
+    let runs: Vec<DayRun> = if parallel {
+        thread::scope(|scope| {
+            let handles: Vec<_> = days
+                .iter()
+                .map(|&puzzle| scope.spawn(move || run_day(puzzle, answers, limits)))
+                .collect();
+            handles
+                .into_iter()
+                .zip(&days)
+                .map(|(handle, puzzle)| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| DayRun::Failed(puzzle.day(), "panicked".to_string()))
+                })
+                .collect()
+        })
+    } else {
+        days.iter()
+            .map(|&puzzle| run_day(puzzle, answers, limits))
+            .collect()
+    };
+
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for run in runs {
+        match run {
+            DayRun::Solved(day_rows) => rows.extend(day_rows),
+            DayRun::Skipped(day, reason) => eprintln!("Skipped day {day:02}: {reason}"),
+            DayRun::Failed(day, reason) => failures.push(format!("day {day:02}: {reason}")),
+        }
+    }
+    print!("{}", table(&rows));
+
+    failures.extend(rows.iter().filter_map(|row| match &row.outcome {
+        Outcome::Answered {
+            answer,
+            verdict: Verdict::Fail { expected },
+            ..
+        } => Some(format!(
+            "day {:02} part {}: expected {expected}, got {answer}",
+            row.day, row.part
+        )),
+        Outcome::Failed(reason) => Some(format!("day {:02} part {}: {reason}", row.day, row.part)),
+        Outcome::Answered { .. } => None,
+    }));
+    if failures.is_empty() {
+        return Ok(());
+    }
+    for failure in &failures {
+        eprintln!("{failure}");
+    }
+    Err(format!("{} check(s) failed", failures.len()).into())
+}
+
+fn run_day(puzzle: &dyn Puzzle, answers: &Answers, limits: Option<Limits>) -> DayRun {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let parts: Vec<Part> = Part::ALL
+        .into_iter()
+        .filter(|&part| puzzle.is_implemented(part))
+        .collect();
+    if parts.is_empty() {
+        return DayRun::Skipped(day, "no part is solved yet".to_string());
+    }
+    let input = match InputSource::resolve(None, year, day, &day_dir(day)).read(day) {
+        Ok(input) => input,
+        Err(err @ InputError::NotProvided { .. }) => return DayRun::Skipped(day, err.to_string()),
+        Err(err) => return DayRun::Failed(day, err.to_string()),
+    };
+    let results = match limits {
+        Some(limits) => run_isolated(year, day, &parts, &input, limits),
+        None => match aoc_core::run(puzzle, &input, &parts) {
+            Ok(report) => Ok(report
+                .parts
+                .into_iter()
+                .map(|result| (result.part, Ok((result.answer, result.elapsed))))
+                .collect()),
+            Err(err) => Err(err.to_string()),
+        },
+    };
+    let results = match results {
+        Ok(results) => results,
+        Err(err) => return DayRun::Failed(day, err),
+    };
+    let rows = results
+        .into_iter()
+        .map(|(part, result)| {
+            let outcome = match result {
+                Ok((answer, elapsed)) => Outcome::Answered {
+                    verdict: answers.verdict(year, day, part, &answer),
+                    answer: answer.to_string(),
+                    elapsed,
+                },
+                Err(failure) => Outcome::Failed(failure.to_string()),
+            };
+            Row { day, part, outcome }
+        })
+        .collect();
+    DayRun::Solved(rows)
+}
+
+type PartRun = (Part, Result<(Answer, Duration), Failure>);
+
+fn run_isolated(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    input: &str,
+    limits: Limits,
+) -> Result<Vec<PartRun>, String> {
+    parts
+        .iter()
+        .map(|&part| {
+            let result = isolate::solve(year, day, part, input, limits)
+                .map_err(|err| format!("could not start part {part}: {err}"))?;
+            Ok((part, result))
+        })
+        .collect()
+}
+
+/// The rows as an aligned table. `new` marks answers not yet in `answers.toml`.
+pub fn table(rows: &[Row]) -> String {
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|row| {
+            let (answer, time, status) = match &row.outcome {
+                Outcome::Answered {
+                    answer,
+                    elapsed,
+                    verdict,
+                } => (
+                    answer.clone(),
+                    format!("{elapsed:.2?}"),
+                    match verdict {
+                        Verdict::Pass => "ok".to_string(),
+                        Verdict::Fail { expected } => format!("FAILED, expected {expected}"),
+                        Verdict::Unknown => "new".to_string(),
+                    },
+                ),
+                Outcome::Failed(reason) => (
+                    "–".to_string(),
+                    "–".to_string(),
+                    format!("FAILED, {reason}"),
+                ),
+            };
+            [
+                row.day.to_string(),
+                row.part.to_string(),
+                answer,
+                time,
+                status,
+            ]
+        })
+        .collect();
+    let header = ["Day", "Part", "Answer", "Time", "Status"].map(String::from);
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&cells)
+                .map(|cells| cells[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut out = String::new();
+    for line in std::iter::once(&header).chain(&cells) {
+        let [day, part, answer, time, status] = line;
+        let _ = writeln!(
+            out,
+            "{day:>w0$}  {part:>w1$}  {answer:>w2$}  {time:>w3$}  {status}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Day, ParseError, Solution, Unsolved};
+
+    use super::*;
+
+    /// A day whose parts are not written yet, and that has no input.
+    struct Stub;
+
+    impl Solution for Stub {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Stub";
+
+        type Input = ();
+        type PartOne = Unsolved;
+        type PartTwo = Unsolved;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> Unsolved {
+            Unsolved
+        }
+
+        fn part_two(_input: &Self::Input) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_stubs_are_skipped_before_reading_their_input() {
+        let run = run_day(&Day::<Stub>::new(), &Answers::default(), None);
+        assert!(
+            matches!(&run, DayRun::Skipped(25, reason) if reason == "no part is solved yet"),
+            "{run:?}"
+        );
+    }
+
+    fn row(day: u8, part: Part, answer: &str, verdict: Verdict) -> Row {
+        Row {
+            day,
+            part,
+            outcome: Outcome::Answered {
+                answer: answer.to_string(),
+                elapsed: Duration::from_micros(1500),
+                verdict,
+            },
+        }
+    }
+
+    #[test]
+    fn test_table_aligns_columns_and_marks_status() {
+        let rows = [
+            row(5, Part::One, "733", Verdict::Pass),
+            row(
+                5,
+                Part::Two,
+                "345821388687084",
+                Verdict::Fail {
+                    expected: "1".to_string(),
+                },
+            ),
+            row(12, Part::One, "7", Verdict::Unknown),
+            Row {
+                day: 12,
+                part: Part::Two,
+                outcome: Outcome::Failed("timed out after 30s".to_string()),
+            },
+        ];
+        assert_eq!(
+            table(&rows),
+            "Day  Part           Answer    Time  Status\n  \
+             5     1              733  1.50ms  ok\n  \
+             5     2  345821388687084  1.50ms  FAILED, expected 1\n \
+             12     1                7  1.50ms  new\n \
+             12     2                –       –  FAILED, timed out after 30s\n"
+        );
+    }
+}
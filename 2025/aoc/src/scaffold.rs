@@ -0,0 +1,346 @@
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::PLACEHOLDER;
+use toml_edit::{DocumentMut, InlineTable, Item, Key, Value, value};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const FUZZ_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs.tmpl");
+
+/// Creates `dayNN` from the templates and registers it with the workspace and
+/// the runner, and with the fuzz targets if the workspace has them. Nothing is
+/// left behind if any step fails.
+pub fn new_day(workspace: &Path, year: u16, day: u8, title: &str) -> Result<(), Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, not {day}").into());
+    }
+    let name = format!("day{day:02}");
+    let day_dir = workspace.join(&name);
+    if day_dir.exists() {
+        return Err(format!(
+            "{} already exists, refusing to overwrite it",
+            day_dir.display()
+        )
+        .into());
+    }
+
+    let render = |template: &str| {
+        template
+            .replace("{{nn}}", &format!("{day:02}"))
+            .replace("{{day}}", &day.to_string())
+            .replace("{{year}}", &year.to_string())
+            // Rendered as a Rust string literal, so quotes and backslashes are escaped.
+            .replace("{{title}}", &format!("{title:?}"))
+    };
+    let mut files = vec![
+        (day_dir.join("Cargo.toml"), render(CARGO_TEMPLATE)),
+        (day_dir.join("src/lib.rs"), render(LIB_TEMPLATE)),
+        (day_dir.join("src/main.rs"), render(MAIN_TEMPLATE)),
+        (day_dir.join("examples/sample.txt"), String::new()),
+        (day_dir.join("input.txt"), format!("{PLACEHOLDER}\n")),
+    ];
+    // Every registration is worked out before anything is written, so a
+    // manifest with an unexpected layout leaves the workspace untouched.
+    let mut edits = vec![
+        edit(&workspace.join("Cargo.toml"), |manifest| {
+            add_member(manifest, &name)
+        })?,
+        edit(&workspace.join("aoc/Cargo.toml"), |manifest| {
+            add_dependency(manifest, &name)
+        })?,
+        edit(&workspace.join("aoc/src/puzzles.rs"), |registry| {
+            add_puzzle(registry, day)
+        })?,
+    ];
+    let fuzz_dir = workspace.join("fuzz");
+    if fuzz_dir.join("Cargo.toml").exists() {
+        files.push((
+            fuzz_dir.join(format!("fuzz_targets/{name}.rs")),
+            render(FUZZ_TEMPLATE),
+        ));
+        edits.push(edit(&fuzz_dir.join("Cargo.toml"), |manifest| {
+            add_dependency(manifest, &name).map(|manifest| add_fuzz_target(&manifest, &name))
+        })?);
+    }
+
+    if let Err(err) = write_all(&files, &edits) {
+        for (path, _) in &files {
+            let _ = fs::remove_file(path);
+        }
+        let _ = fs::remove_dir_all(&day_dir);
+        for edit in &edits {
+            let _ = fs::write(&edit.path, &edit.original);
+        }
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+/// A registration file rewritten by [`new_day`], with its original contents
+/// so a failed scaffold can be undone.
+struct Edit {
+    path: PathBuf,
+    original: String,
+    updated: String,
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Option<String>) -> Result<Edit, Box<dyn Error>> {
+    let original = fs::read_to_string(path)?;
+    let updated = f(&original).ok_or_else(|| format!("unexpected layout in {}", path.display()))?;
+    Ok(Edit {
+        path: path.to_path_buf(),
+        original,
+        updated,
+    })
+}
+
+fn write_all(files: &[(PathBuf, String)], edits: &[Edit]) -> io::Result<()> {
+    for (path, contents) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    for edit in edits {
+        fs::write(&edit.path, &edit.updated)?;
+    }
+    Ok(())
+}
+
+/// Adds `name` to the workspace members, in order, keeping the list's comments
+/// and layout.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let mut manifest: DocumentMut = manifest.parse().ok()?;
+    let members = manifest
+        .get_mut("workspace")?
+        .get_mut("members")?
+        .as_array_mut()?;
+    if members.iter().any(|member| member.as_str() == Some(name)) {
+        return Some(manifest.to_string());
+    }
+    let at = members
+        .iter()
+        .position(|member| member.as_str().is_some_and(|member| member > name))
+        .unwrap_or(members.len());
+    let mut member = Value::from(name);
+    // Indent like a neighbour, without taking over a comment above it.
+    if let Some(prefix) = members
+        .get(at)
+        .or_else(|| members.get(at.wrapping_sub(1)))
+        .and_then(|neighbour| neighbour.decor().prefix()?.as_str())
+    {
+        let indent = prefix.rfind('\n').map_or(prefix, |line| &prefix[line..]);
+        member.decor_mut().set_prefix(indent);
+    }
+    members.insert_formatted(at, member);
+    Some(manifest.to_string())
+}
+
+/// Adds a path dependency on `name` among the other day crates, in order.
+fn add_dependency(manifest: &str, name: &str) -> Option<String> {
+    let mut manifest: DocumentMut = manifest.parse().ok()?;
+    let dependencies = manifest.get_mut("dependencies")?.as_table_mut()?;
+    if dependencies.contains_key(name) {
+        return Some(manifest.to_string());
+    }
+    let keys: Vec<String> = dependencies
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .collect();
+    let is_day = |key: &String| key.starts_with("day");
+    let at = keys
+        .iter()
+        .position(|key| is_day(key) && key.as_str() > name)
+        .or_else(|| keys.iter().rposition(is_day).map(|last| last + 1))
+        .unwrap_or(keys.len());
+    // Tables only append, so the entries after the new one are moved behind it.
+    let moved: Vec<(Key, Item)> = keys[at..]
+        .iter()
+        .filter_map(|key| dependencies.remove_entry(key))
+        .collect();
+    let mut path = InlineTable::new();
+    path.insert("path", format!("../{name}").into());
+    dependencies.insert(name, value(path));
+    for (key, item) in moved {
+        dependencies.insert_formatted(&key, item);
+    }
+    Some(manifest.to_string())
+}
+
+fn add_puzzle(registry: &str, day: u8) -> Option<String> {
+    let end = registry.find("\n];")?;
+    Some(format!(
+        "{}\n    &Day::<day{day:02}::Day{day:02}>::new(),{}",
+        &registry[..end],
+        &registry[end..]
+    ))
+}
+
+fn add_fuzz_target(manifest: &str, name: &str) -> String {
+    format!(
+        "{manifest}\n[[bin]]\nname = \"{name}\"\npath = \"fuzz_targets/{name}.rs\"\n\
+         test = false\ndoc = false\nbench = false\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("aoc/src")).unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("aoc/Cargo.toml"),
+            "[dependencies]\nclap.workspace = true\nday01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("aoc/src/puzzles.rs"),
+            "pub static PUZZLES: &[&dyn Puzzle] = &[\n    &Day::<day01::Day01>::new(),\n];\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_new_day_creates_crate_and_registers_it() {
+        let dir = workspace();
+        new_day(dir.path(), 2025, 2, "Gift Shop").unwrap();
+
+        let lib = fs::read_to_string(dir.path().join("day02/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02;"));
+        assert!(lib.contains("const DAY: u8 = 2;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Gift Shop\";"));
+        assert!(dir.path().join("day02/src/main.rs").exists());
+        assert!(dir.path().join("day02/examples/sample.txt").exists());
+        assert_eq!(
+            aoc_core::check(&fs::read_to_string(dir.path().join("day02/input.txt")).unwrap()),
+            Some(aoc_core::MissingInput::Placeholder)
+        );
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n]\n"
+        );
+        assert!(
+            fs::read_to_string(dir.path().join("aoc/Cargo.toml"))
+                .unwrap()
+                .ends_with("day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n")
+        );
+        assert!(
+            fs::read_to_string(dir.path().join("aoc/src/puzzles.rs"))
+                .unwrap()
+                .contains("    &Day::<day01::Day01>::new(),\n    &Day::<day02::Day02>::new(),\n];")
+        );
+    }
+
+    #[test]
+    fn test_new_day_adds_a_fuzz_target_when_fuzzing_is_set_up() {
+        let dir = workspace();
+        fs::create_dir_all(dir.path().join("fuzz/fuzz_targets")).unwrap();
+        fs::write(
+            dir.path().join("fuzz/Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../day01\" }\n\n[[bin]]\nname = \"day01\"\n",
+        )
+        .unwrap();
+        new_day(dir.path(), 2025, 2, "Gift Shop").unwrap();
+
+        let target = fs::read_to_string(dir.path().join("fuzz/fuzz_targets/day02.rs")).unwrap();
+        assert!(target.contains("aoc_fuzz::parse::<Day02>(input)"));
+        let manifest = fs::read_to_string(dir.path().join("fuzz/Cargo.toml")).unwrap();
+        assert!(
+            manifest.contains("day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n")
+        );
+        assert!(manifest.ends_with("[[bin]]\nname = \"day02\"\npath = \"fuzz_targets/day02.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+    }
+
+    #[test]
+    fn test_new_day_escapes_the_title() {
+        let dir = workspace();
+        new_day(dir.path(), 2025, 2, r#"The "Quoted" \ Day"#).unwrap();
+        let lib = fs::read_to_string(dir.path().join("day02/src/lib.rs")).unwrap();
+        assert!(lib.contains(r#"const TITLE: &'static str = "The \"Quoted\" \\ Day";"#));
+    }
+
+    #[test]
+    fn test_new_day_rejects_days_outside_the_advent() {
+        let dir = workspace();
+        for day in [0, 26] {
+            let err = new_day(dir.path(), 2025, day, "Nope").unwrap_err();
+            assert!(err.to_string().contains("between 1 and 25"));
+        }
+        assert!(!dir.path().join("day00").exists());
+        assert!(!dir.path().join("day26").exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_registers_in_order_keeping_comments() {
+        let dir = workspace();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    # Puzzles\n    \"day01\",\n    \"day03\", # last\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("aoc/Cargo.toml"),
+            "[dependencies]\nclap.workspace = true\nday01 = { path = \"../day01\" }\n\
+             day03 = { path = \"../day03\" }\n",
+        )
+        .unwrap();
+        new_day(dir.path(), 2025, 2, "Gift Shop").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    # Puzzles\n    \"day01\",\n    \"day02\",\n    \"day03\", # last\n]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nclap.workspace = true\nday01 = { path = \"../day01\" }\n\
+             day02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_leaves_nothing_behind_when_registering_fails() {
+        let dir = workspace();
+        fs::write(dir.path().join("aoc/src/puzzles.rs"), "// moved\n").unwrap();
+        let err = new_day(dir.path(), 2025, 2, "Gift Shop").unwrap_err();
+        assert!(err.to_string().contains("unexpected layout"));
+
+        assert!(!dir.path().join("day02").exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n"
+        );
+        fs::write(
+            dir.path().join("aoc/src/puzzles.rs"),
+            "pub static PUZZLES: &[&dyn Puzzle] = &[\n];\n",
+        )
+        .unwrap();
+        new_day(dir.path(), 2025, 2, "Gift Shop").unwrap();
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let dir = workspace();
+        fs::create_dir_all(dir.path().join("day01")).unwrap();
+        let err = new_day(dir.path(), 2025, 1, "Secret Entrance").unwrap_err();
+        assert!(err.to_string().contains("refusing to overwrite"));
+        assert_eq!(fs::read_dir(dir.path().join("day01")).unwrap().count(), 0);
+    }
+}
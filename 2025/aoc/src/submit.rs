@@ -0,0 +1,336 @@
+use std::{error::Error, fs, io, path::Path};
+
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted before the previous attempt's cooldown ran out; the answer was not checked.
+    TooSoon,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    Unrecognised,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+/// The outcome of a submission and how many seconds to wait before the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub wait: Option<u64>,
+}
+
+impl Response {
+    /// Reads the `<article>` of the page the site answers a submission with.
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+        let outcome = if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognised
+        };
+        Self {
+            outcome,
+            wait: left_to_wait(&text).or_else(|| wait_before_retrying(&text)),
+        }
+    }
+}
+
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::with_capacity(body.len());
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 1m 5s left to wait."
+fn left_to_wait(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .try_fold(0, |total, amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(total + value * 3600),
+                "m" => Some(total + value * 60),
+                "s" => Some(total + value),
+                _ => None,
+            }
+        })
+}
+
+/// "Please wait one minute before trying again." / "...wait 5 minutes before..."
+fn wait_before_retrying(text: &str) -> Option<u64> {
+    let lower = text.to_lowercase();
+    let start = lower.find("please wait ")? + "please wait ".len();
+    let mut words = lower[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" | "a" => 1,
+        amount => amount.parse().ok()?,
+    };
+    words.next()?.starts_with("minute").then_some(minutes * 60)
+}
+
+/// Every answer ever submitted from this checkout, stored as TOML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the attempt.
+    pub submitted_at: u64,
+    /// Unix time before which the site will not accept another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| format!("invalid history {}: {err}", path.display()).into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        response: Response,
+        now: u64,
+    ) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            outcome: response.outcome,
+            submitted_at: now,
+            retry_at: response.wait.map(|wait| now + wait),
+        });
+    }
+
+    /// Why `answer` must not be sent at `now`, if it must not: the part is
+    /// already solved, the answer is known to be wrong (exactly, or beyond a
+    /// too-high/too-low bound), or the site's cooldown has not run out.
+    pub fn refusal(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Option<String> {
+        let attempts = || {
+            self.submissions
+                .iter()
+                .filter(move |s| (s.year, s.day, s.part) == (year, day, part.number()))
+        };
+        if let Some(solved) = attempts().find(|s| s.outcome == Outcome::Correct) {
+            return Some(format!(
+                "part {part} was already solved with {}",
+                solved.answer
+            ));
+        }
+        if let Some(wrong) = attempts().find(|s| s.outcome.is_wrong() && s.answer == answer) {
+            let outcome = match wrong.outcome {
+                Outcome::TooHigh => "too high",
+                Outcome::TooLow => "too low",
+                _ => "wrong",
+            };
+            return Some(format!("{answer} was already submitted and is {outcome}"));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                attempts()
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Outcome::TooHigh).filter(|&high| value >= high).min() {
+                return Some(format!("{answer} is not below {high}, which is too high"));
+            }
+            if let Some(low) = bound(Outcome::TooLow).filter(|&low| value <= low).max() {
+                return Some(format!("{answer} is not above {low}, which is too low"));
+            }
+        }
+        self.submissions
+            .iter()
+            .filter_map(|s| s.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now)
+            .map(|retry_at| format!("the site asks to wait another {}s", retry_at - now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><main><article><p>{article}</p></article></main></html>")
+    }
+
+    #[test]
+    fn test_parses_right_answer() {
+        let response = Response::parse(&page(
+            "That's the right answer!  You are <span>one gold star</span> closer.",
+        ));
+        assert_eq!(response.outcome, Outcome::Correct);
+        assert_eq!(response.wait, None);
+    }
+
+    #[test]
+    fn test_parses_too_high_with_retry_wait() {
+        let response = Response::parse(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, \
+             make sure you're using the full input data. Please wait one minute before \
+             trying again. <a href=\"/2025/day/5\">[Return to Day 5]</a>",
+        ));
+        assert_eq!(response.outcome, Outcome::TooHigh);
+        assert_eq!(response.wait, Some(60));
+    }
+
+    #[test]
+    fn test_parses_cooldown() {
+        let response = Response::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.",
+        ));
+        assert_eq!(response.outcome, Outcome::TooSoon);
+        assert_eq!(response.wait, Some(65));
+    }
+
+    #[test]
+    fn test_parses_wrong_level() {
+        let response = Response::parse(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?",
+        ));
+        assert_eq!(response.outcome, Outcome::WrongLevel);
+    }
+
+    fn answered(outcome: Outcome, wait: Option<u64>) -> Response {
+        Response { outcome, wait }
+    }
+
+    #[test]
+    fn test_refuses_known_wrong_answers_and_bounds() {
+        let mut history = History::default();
+        history.record(
+            2025,
+            5,
+            Part::One,
+            "700",
+            answered(Outcome::TooLow, None),
+            0,
+        );
+        history.record(
+            2025,
+            5,
+            Part::One,
+            "800",
+            answered(Outcome::TooHigh, None),
+            0,
+        );
+        history.record(2025, 5, Part::One, "750", answered(Outcome::Wrong, None), 0);
+
+        assert!(history.refusal(2025, 5, Part::One, "750", 100).is_some());
+        assert!(history.refusal(2025, 5, Part::One, "800", 100).is_some());
+        assert!(history.refusal(2025, 5, Part::One, "900", 100).is_some());
+        assert!(history.refusal(2025, 5, Part::One, "650", 100).is_some());
+        assert_eq!(history.refusal(2025, 5, Part::One, "733", 100), None);
+        assert_eq!(history.refusal(2025, 5, Part::Two, "750", 100), None);
+    }
+
+    #[test]
+    fn test_refuses_during_cooldown_and_after_solving() {
+        let mut history = History::default();
+        history.record(
+            2025,
+            6,
+            Part::One,
+            "1",
+            answered(Outcome::Wrong, Some(60)),
+            1000,
+        );
+        assert!(history.refusal(2025, 6, Part::One, "2", 1030).is_some());
+        assert_eq!(history.refusal(2025, 6, Part::One, "2", 1060), None);
+
+        history.record(
+            2025,
+            6,
+            Part::One,
+            "2",
+            answered(Outcome::Correct, None),
+            1060,
+        );
+        assert!(history.refusal(2025, 6, Part::One, "3", 2000).is_some());
+    }
+
+    #[test]
+    fn test_history_round_trips_through_toml() {
+        let mut history = History::default();
+        history.record(
+            2025,
+            7,
+            Part::Two,
+            "40",
+            answered(Outcome::TooSoon, Some(30)),
+            5,
+        );
+        let reparsed: History = toml::from_str(&toml::to_string(&history).unwrap()).unwrap();
+        assert_eq!(reparsed, history);
+    }
+}
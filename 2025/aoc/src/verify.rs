@@ -0,0 +1,88 @@
+use std::error::Error;
+
+use aoc_core::{Answers, Part, Verdict};
+
+use crate::{day_dir, puzzles};
+
+/// Re-runs every day with recorded answers and fails if any of them changed,
+/// or if no recorded answer matches the filter.
+pub fn verify(answers: &Answers, year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    let days = answers
+        .days()
+        .into_iter()
+        .filter(|&(y, d)| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d));
+    for (year, day) in days {
+        let Some(puzzle) = puzzles::find(year, day) else {
+            failures.push(format!("{year} day {day:02}: no solution registered"));
+            continue;
+        };
+        let input = match aoc_core::InputSource::resolve(None, year, day, &day_dir(day)).read(day) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{year} day {day:02}: {err}"));
+                continue;
+            }
+        };
+        let parts: Vec<Part> = Part::ALL
+            .into_iter()
+            .filter(|&part| answers.get(year, day, part).is_some())
+            .collect();
+        let report = match aoc_core::run(puzzle, &input, &parts) {
+            Ok(report) => report,
+            Err(err) => {
+                failures.push(format!("{year} day {day:02}: {err}"));
+                continue;
+            }
+        };
+        for result in report.parts {
+            checked += 1;
+            let label = format!("{year} day {day:02} part {}", result.part);
+            match answers.verdict(year, day, result.part, &result.answer) {
+                Verdict::Pass => println!("{label}: ok ({})", result.answer),
+                Verdict::Fail { expected } => {
+                    println!("{label}: FAILED");
+                    failures.push(format!(
+                        "{label}: expected {expected}, got {}",
+                        result.answer
+                    ));
+                }
+                Verdict::Unknown => {}
+            }
+        }
+    }
+
+    if failures.is_empty() && checked == 0 {
+        let filter = match (year, day) {
+            (Some(year), Some(day)) => format!(" for {year} day {day:02}"),
+            (Some(year), None) => format!(" for {year}"),
+            (None, Some(day)) => format!(" for day {day:02}"),
+            (None, None) => String::new(),
+        };
+        return Err(format!("no recorded answers{filter}").into());
+    }
+    if failures.is_empty() {
+        println!("All {checked} recorded answers still match.");
+        return Ok(());
+    }
+    for failure in &failures {
+        eprintln!("{failure}");
+    }
+    Err(format!("{} recorded answer check(s) failed", failures.len()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verifying_nothing_fails() {
+        let mut answers = Answers::default();
+        answers.set(2025, 5, Part::One, "733");
+        let err = verify(&answers, Some(2025), Some(15)).unwrap_err();
+        assert_eq!(err.to_string(), "no recorded answers for 2025 day 15");
+        assert!(verify(&Answers::default(), None, None).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::Part;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time and size of every watched file.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Re-runs `day`'s tests and solution whenever its crate, the shared
+/// `aoc-core` sources or its input change, until interrupted.
+pub fn watch(workspace: &Path, day: u8, input: &Path) -> Result<(), Box<dyn Error>> {
+    let package = format!("day{day:02}");
+    let watched = [
+        workspace.join(&package),
+        workspace.join("aoc-core/src"),
+        input.to_path_buf(),
+    ];
+    println!(
+        "Watching {package}, aoc-core and {}; press Ctrl-C to stop",
+        input.display()
+    );
+    let mut previous = BTreeMap::new();
+    let mut seen = Snapshot::new();
+    loop {
+        let current = snapshot(&watched)?;
+        if current != seen {
+            // Let editors finish writing before building.
+            thread::sleep(POLL_INTERVAL);
+            seen = snapshot(&watched)?;
+            previous = run(workspace, &package, &previous)?;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs the tests and the solution once, printing the answers next to
+/// `previous`; returns the new answers, or `previous` if the run failed.
+fn run(
+    workspace: &Path,
+    package: &str,
+    previous: &BTreeMap<Part, String>,
+) -> Result<BTreeMap<Part, String>, Box<dyn Error>> {
+    println!("\n── {package} ──");
+    let tests = cargo(workspace)
+        .args(["test", "--quiet", "-p", package])
+        .status()?;
+    println!(
+        "Tests {}",
+        if tests.success() { "passed" } else { "FAILED" }
+    );
+
+    let output = cargo(workspace)
+        .args(["run", "--quiet", "--release", "-p", package])
+        .output()?;
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    if !output.status.success() {
+        println!("Solution failed to run");
+        return Ok(previous.clone());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers = parse_answers(&stdout);
+    for line in stdout.lines() {
+        println!("{line}");
+    }
+    for change in changes(previous, &answers) {
+        println!("{change}");
+    }
+    Ok(answers)
+}
+
+fn cargo(workspace: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(workspace);
+    command
+}
+
+fn snapshot(paths: &[PathBuf]) -> io::Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        collect(path, &mut snapshot)?;
+    }
+    Ok(snapshot)
+}
+
+fn collect(path: &Path, snapshot: &mut Snapshot) -> io::Result<()> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    if metadata.is_dir() {
+        if path.file_name().is_some_and(|name| name == "target") {
+            return Ok(());
+        }
+        for entry in fs::read_dir(path)? {
+            collect(&entry?.path(), snapshot)?;
+        }
+    } else {
+        snapshot.insert(path.to_path_buf(), (metadata.modified()?, metadata.len()));
+    }
+    Ok(())
+}
+
+/// The answers from a day binary's report, skipping unsolved parts.
+fn parse_answers(report: &str) -> BTreeMap<Part, String> {
+    report
+        .lines()
+        .filter_map(|line| {
+            let (part, rest) = line.strip_prefix("Part ")?.split_once(": ")?;
+            let answer = rest.rsplit_once(" (").map_or(rest, |(answer, _)| answer);
+            if answer == "not implemented" {
+                return None;
+            }
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+/// A line per part saying whether its answer changed since the last run.
+fn changes(previous: &BTreeMap<Part, String>, current: &BTreeMap<Part, String>) -> Vec<String> {
+    if previous.is_empty() {
+        return Vec::new();
+    }
+    Part::ALL
+        .into_iter()
+        .filter_map(|part| match (previous.get(&part), current.get(&part)) {
+            (Some(before), Some(now)) if before == now => Some(format!("Part {part}: unchanged")),
+            (Some(before), Some(now)) => {
+                Some(format!("Part {part}: CHANGED from {before} to {now}"))
+            }
+            (None, Some(now)) => Some(format!("Part {part}: newly solved, {now}")),
+            (Some(before), None) => Some(format!("Part {part}: no longer solved, was {before}")),
+            (None, None) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers_reads_the_report() {
+        let report = "Day 5: Cafeteria (Advent of Code 2025)\n\
+                      =========================\n\
+                      Parse: 5µs\n\
+                      Part 1: 733 (7µs)\n\
+                      Part 2: not implemented";
+        assert_eq!(
+            parse_answers(report),
+            BTreeMap::from([(Part::One, "733".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_changes_compare_with_the_previous_run() {
+        let before = BTreeMap::from([(Part::One, "733".to_string())]);
+        let after = BTreeMap::from([
+            (Part::One, "734".to_string()),
+            (Part::Two, "14".to_string()),
+        ]);
+        assert_eq!(changes(&BTreeMap::new(), &after), Vec::<String>::new());
+        assert_eq!(
+            changes(&before, &after),
+            [
+                "Part 1: CHANGED from 733 to 734",
+                "Part 2: newly solved, 14"
+            ]
+        );
+        assert_eq!(changes(&after, &after)[0], "Part 1: unchanged");
+    }
+
+    #[test]
+    fn test_snapshot_notices_edits_and_skips_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = [dir.path().to_path_buf()];
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::create_dir_all(dir.path().join("target")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "fn a() {}").unwrap();
+        let before = snapshot(&paths).unwrap();
+
+        fs::write(dir.path().join("target/out"), "build output").unwrap();
+        assert_eq!(snapshot(&paths).unwrap(), before);
+
+        fs::write(dir.path().join("src/lib.rs"), "fn ab() {}").unwrap();
+        assert_ne!(snapshot(&paths).unwrap(), before);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{fs, process::Command};
+
+/// Runs `aoc run-all --isolate` over the hidden fixture puzzles, one of which
+/// answers, one panics and one hangs, and returns each table row as its cells.
+fn run_fixtures_isolated() -> (bool, Vec<Vec<String>>) {
+    let dir = tempfile::tempdir().unwrap();
+    let inputs = dir.path().join("1");
+    fs::create_dir_all(&inputs).unwrap();
+    fs::write(inputs.join("day01.txt"), "42\n").unwrap();
+    fs::write(inputs.join("day02.txt"), "panic\n").unwrap();
+    fs::write(inputs.join("day03.txt"), "hang\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run-all", "1", "--isolate", "--timeout", "1"])
+        .env("AOC_INPUT_DIR", dir.path())
+        .output()
+        .unwrap();
+    let rows = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .skip(1)
+        .map(|line| line.split_whitespace().map(String::from).collect())
+        .collect();
+    (output.status.success(), rows)
+}
+
+fn cells<'a>(rows: &'a [Vec<String>], day: &str, part: &str) -> Option<&'a [String]> {
+    rows.iter()
+        .find(|row| row[0] == day && row[1] == part)
+        .map(|row| &row[2..])
+}
+
+#[test]
+fn test_each_part_reports_its_own_outcome() {
+    let (success, rows) = run_fixtures_isolated();
+    assert!(!success, "failed parts fail the run");
+
+    let solved = cells(&rows, "1", "1").unwrap();
+    assert_eq!(solved[0], "42");
+
+    let panicked = cells(&rows, "2", "1").unwrap().join(" ");
+    assert!(
+        panicked.contains("FAILED, panicked with `Fixture panics when asked to!`"),
+        "{panicked}"
+    );
+
+    let timed_out = cells(&rows, "3", "1").unwrap().join(" ");
+    assert!(
+        timed_out.ends_with("FAILED, timed out after 1s"),
+        "{timed_out}"
+    );
+}
@@ -0,0 +1,470 @@
+use aoc_core::{ParseError, Rng, Solution, Unsolved, parse};
+
+/// Number of positions on the dial, `0` to `99`.
+pub const DIAL_SIZE: i32 = 100;
+/// Where the dial points before the first rotation.
+pub const START_POS: i32 = 50;
+/// The longest rotation accepted, so the dial position cannot overflow.
+pub const MAX_DISTANCE: i32 = i32::MAX - DIAL_SIZE;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+
+    type Input = Vec<Rotation>;
+    type PartOne = Unsolved;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_rotations(input)
+    }
+
+    fn part_one(_rotations: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_two(rotations: &Self::Input) -> i64 {
+        count_rotations(rotations, START_POS)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rotations: Vec<String> = (0..size)
+            .map(|_| format!("{}{}", rng.pick(&['L', 'R']), rng.range(1..=999)))
+            .collect();
+        Some(rotations.join("\n"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: i32,
+}
+
+impl Rotation {
+    /// Parses a token such as `L68` found in `input`.
+    pub fn parse(input: &str, token: &str) -> Result<Self, ParseError> {
+        let mut chars = token.chars();
+        let direction = chars
+            .next()
+            .and_then(Direction::from_char)
+            .ok_or_else(|| ParseError::at(input, token, "a rotation such as `L68`"))?;
+        let digits = chars.as_str();
+        let expected = format!("a distance from 0 to {MAX_DISTANCE}");
+        let distance: u32 = parse::number(input, digits, &expected)?;
+        let distance = i32::try_from(distance)
+            .ok()
+            .filter(|&distance| distance <= MAX_DISTANCE)
+            .ok_or_else(|| ParseError::at(input, digits, expected))?;
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+/// The dial after some rotations, with how many times it has passed or landed on `0`.
+#[derive(Debug)]
+pub struct DialState {
+    pub position: i32,
+    pub rotations: i64,
+}
+
+impl DialState {
+    pub const fn new(start_pos: i32) -> Self {
+        Self {
+            position: start_pos,
+            rotations: 0,
+        }
+    }
+
+    pub fn apply_rotation(&mut self, rotation: Rotation) {
+        let new_pos = match rotation.direction {
+            Direction::Left => self.position - rotation.distance,
+            Direction::Right => self.position + rotation.distance,
+        };
+
+        let full_rotations = self.calculate_full_rotations(rotation, new_pos);
+        self.rotations += i64::from(full_rotations);
+        self.position = new_pos.rem_euclid(DIAL_SIZE);
+
+        // Landing on `0` from the right counts once; `L0` never moves the dial.
+        if rotation.direction == Direction::Left
+            && rotation.distance > 0
+            && self.position == 0
+            && full_rotations == 0
+        {
+            self.rotations += 1;
+        }
+    }
+
+    fn calculate_full_rotations(&self, rotation: Rotation, new_position: i32) -> i32 {
+        match rotation.direction {
+            Direction::Left if new_position < 0 => {
+                let offset = if self.position == 0 { 0 } else { DIAL_SIZE };
+                (rotation.distance + offset - self.position) / DIAL_SIZE
+            }
+            Direction::Right if new_position >= DIAL_SIZE => new_position / DIAL_SIZE,
+            _ => 0,
+        }
+    }
+}
+
+/// Parses whitespace-separated rotations.
+pub fn parse_rotations(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|token| Rotation::parse(input, token))
+        .collect()
+}
+
+/// How many times the dial points at `0` while applying `rotations` from `start_pos`.
+pub fn count_rotations(rotations: &[Rotation], start_pos: i32) -> i64 {
+    rotations
+        .iter()
+        .fold(DialState::new(start_pos), |mut state, &rotation| {
+            state.apply_rotation(rotation);
+            state
+        })
+        .rotations
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{
+        Part,
+        oracle::{
+            check_against,
+            proptest::prelude::{Strategy, prop},
+        },
+    };
+
+    use super::*;
+
+    fn solution(input: &str, start_pos: i32) -> i64 {
+        count_rotations(&parse_rotations(input).unwrap(), start_pos)
+    }
+
+    #[test]
+    fn test_single_rotation_right() {
+        let input = "R10";
+        assert_eq!(solution(input, START_POS), 0);
+    }
+
+    #[test]
+    fn test_single_rotation_land_on_0() {
+        let input = "L50";
+        assert_eq!(solution(input, START_POS), 1);
+    }
+
+    #[test]
+    fn test_overshoot_rotation() {
+        let input = "L60";
+        assert_eq!(solution(input, START_POS), 1);
+    }
+
+    #[test]
+    fn test_overshoot_multiple_r_rotation() {
+        let input = "R5500";
+        assert_eq!(solution(input, START_POS), 55);
+    }
+
+    #[test]
+    fn test_overshoot_multiple_l_rotation() {
+        let input = "L200";
+        assert_eq!(solution(input, START_POS), 2);
+    }
+
+    #[test]
+    fn test_right_land_exactly_on_zero() {
+        let input = "R50";
+        assert_eq!(solution(input, START_POS), 1);
+    }
+
+    #[test]
+    fn test_right_exactly_100() {
+        let input = "R100";
+        assert_eq!(solution(input, START_POS), 1);
+    }
+
+    #[test]
+    fn test_from_position_52() {
+        let input = "R48";
+        assert_eq!(solution(input, 52), 1);
+    }
+
+    #[test]
+    fn test_from_position_14_l_82() {
+        let input = "L82";
+        assert_eq!(solution(input, 14), 1);
+    }
+
+    #[test]
+    fn test_start_at_0_go_right_small() {
+        let input = "R1";
+        assert_eq!(solution(input, 0), 0);
+    }
+
+    #[test]
+    fn test_start_at_0_go_left_small() {
+        let input = "L1";
+        assert_eq!(solution(input, 0), 0);
+    }
+
+    #[test]
+    fn test_start_at_0_go_right_100() {
+        let input = "R100";
+        assert_eq!(solution(input, 0), 1);
+    }
+
+    #[test]
+    fn test_start_at_0_go_left_100() {
+        let input = "L100";
+        assert_eq!(solution(input, 0), 1);
+    }
+
+    #[test]
+    fn test_from_99_right_1() {
+        let input = "R1";
+        assert_eq!(solution(input, 99), 1);
+    }
+
+    #[test]
+    fn test_from_99_right_2() {
+        let input = "R2";
+        assert_eq!(solution(input, 99), 1);
+    }
+
+    #[test]
+    fn test_from_99_right_101() {
+        let input = "R101";
+        assert_eq!(solution(input, 99), 2);
+    }
+
+    #[test]
+    fn test_land_on_0_from_various_positions() {
+        assert_eq!(solution("R50", 50), 1);
+        assert_eq!(solution("L50", 50), 1);
+    }
+
+    #[test]
+    fn test_distance_1() {
+        assert_eq!(solution("R1", 50), 0);
+        assert_eq!(solution("L1", 50), 0);
+    }
+
+    #[test]
+    fn test_two_steps_both_land_on_zero() {
+        let input = "L50 R100";
+        assert_eq!(solution(input, 50), 2);
+    }
+
+    #[test]
+    fn test_forward_backward() {
+        let input = "R50 L50";
+        assert_eq!(solution(input, 50), 1);
+    }
+
+    #[test]
+    fn test_three_full_rotations() {
+        let input = "R300";
+        assert_eq!(solution(input, 50), 3);
+    }
+
+    #[test]
+    fn test_from_1_left_1() {
+        let input = "L1";
+        assert_eq!(solution(input, 1), 1);
+    }
+
+    #[test]
+    fn test_from_1_left_2() {
+        let input = "L2";
+        assert_eq!(solution(input, 1), 1);
+    }
+
+    #[test]
+    fn test_from_1_left_101() {
+        let input = "L101";
+        assert_eq!(solution(input, 1), 2);
+    }
+
+    #[test]
+    fn test_right_exactly_to_0() {
+        let input = "R70";
+        assert_eq!(
+            solution(input, 30),
+            1,
+            "RIGHT landing on 0 must be counted!"
+        );
+    }
+
+    #[test]
+    fn test_sequence_with_right_landing() {
+        let input = "R50 L100";
+        assert_eq!(solution(input, 50), 2);
+    }
+
+    #[test]
+    fn test_left_150_from_50() {
+        let input = "L150";
+        assert_eq!(solution(input, 50), 2);
+    }
+
+    #[test]
+    fn test_left_201_from_1() {
+        let input = "L201";
+        assert_eq!(solution(input, 1), 3);
+    }
+
+    #[test]
+    fn test_left_199_from_99() {
+        let input = "L199";
+        assert_eq!(solution(input, 99), 2);
+    }
+
+    #[test]
+    fn test_left_multiple_full_rotations_from_zero() {
+        let input = "L200";
+        assert_eq!(solution(input, 0), 2);
+    }
+
+    #[test]
+    fn test_left_multiple_full_rotations_from_small_pos() {
+        let input = "L210";
+        assert_eq!(solution(input, 10), 3);
+    }
+
+    #[test]
+    fn test_left_small_no_wrap() {
+        let input = "L10";
+        assert_eq!(solution(input, 50), 0);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(solution("", START_POS), 0);
+    }
+
+    #[test]
+    fn test_whitespace_only_input() {
+        assert_eq!(solution("   \n\t  ", START_POS), 0);
+    }
+
+    #[test]
+    fn test_invalid_direction_is_rejected() {
+        let err = Day01::parse("R5 X10").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+
+    #[test]
+    fn test_zero_distance_no_cross() {
+        assert_eq!(solution("R0", START_POS), 0);
+        assert_eq!(solution("L0", START_POS), 0);
+        assert_eq!(solution("L0", 0), 0);
+    }
+
+    #[test]
+    fn test_mixed_valid_and_invalid_tokens() {
+        let err = Day01::parse("R30\nX5 L20").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Day01::parse("R30 L").is_err());
+        assert!(Day01::parse("R30 L-5").is_err());
+    }
+
+    #[test]
+    fn test_distances_that_could_overflow_are_rejected() {
+        let err = Day01::parse("R2147483647").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+        assert_eq!(solution(&format!("R{MAX_DISTANCE}"), 99), 21_474_836);
+        assert_eq!(solution(&format!("L{MAX_DISTANCE}"), 1), 21_474_836);
+    }
+
+    #[test]
+    fn test_newline_separated_tokens() {
+        let input = "R70\nR40";
+        assert_eq!(solution(input, START_POS), 1);
+    }
+
+    #[test]
+    fn test_exact_multiple_rotations_from_zero() {
+        assert_eq!(solution("R200", 0), 2);
+        assert_eq!(solution("R300", 0), 3);
+    }
+
+    #[test]
+    fn test_zig_zag_crossing() {
+        let input = "L2 R2";
+        assert_eq!(solution(input, 1), 2);
+    }
+
+    #[test]
+    fn test_alternating_around_zero() {
+        let input = "R2 L2";
+        assert_eq!(solution(input, 99), 2);
+    }
+
+    /// Turns the dial one click at a time, counting every click that lands on `0`.
+    fn click_by_click(rotations: &[Rotation]) -> i64 {
+        let mut position = START_POS;
+        let mut zeros = 0;
+        for rotation in rotations {
+            let step = match rotation.direction {
+                Direction::Left => -1,
+                Direction::Right => 1,
+            };
+            for _ in 0..rotation.distance {
+                position = (position + step).rem_euclid(DIAL_SIZE);
+                if position == 0 {
+                    zeros += 1;
+                }
+            }
+        }
+        zeros
+    }
+
+    #[test]
+    fn test_matches_click_by_click() {
+        let rotations =
+            prop::collection::vec((prop::sample::select(vec!['L', 'R']), 0..350), 0..20).prop_map(
+                |rotations| {
+                    rotations
+                        .iter()
+                        .map(|(direction, distance)| format!("{direction}{distance}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                },
+            );
+        check_against::<Day01, _>(Part::Two, rotations, |rotations| click_by_click(rotations));
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day01::generate(&mut Rng::new(1), 50).unwrap();
+        assert_eq!(Day01::parse(&input).unwrap().len(), 50);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
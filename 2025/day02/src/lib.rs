@@ -0,0 +1,142 @@
+use aoc_core::{IntervalSet, ParseError, Rng, Solution, Unsolved, parse};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+
+    type Input = IntervalSet;
+    type PartOne = Unsolved;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let ranges = parse::separated(input, ',', |range| {
+            parse::range(input, range, "a range like `11-22`", "a product ID")
+        })?;
+        Ok(ranges.into_iter().collect())
+    }
+
+    fn part_one(_ids: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_two(ids: &Self::Input) -> u64 {
+        sum_repeating_patterns(ids)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut end = 0;
+        let ranges: Vec<String> = (0..size)
+            .map(|_| {
+                let start = end + rng.range(1..=1_000_000);
+                end = start + rng.range(0..=50_000);
+                format!("{start}-{end}")
+            })
+            .collect();
+        Some(ranges.join(","))
+    }
+}
+
+/// Whether the ID is some sequence of digits repeated at least twice.
+pub fn check_repeating_pattern(digit: &u64) -> bool {
+    let digit_str = digit.to_string();
+    let digit_bytes = digit_str.as_bytes();
+    (1..digit_bytes.len())
+        .filter(|&chunk_size| digit_bytes.len().is_multiple_of(chunk_size))
+        .any(|chunk_size| {
+            let first_chunk = &digit_bytes[..chunk_size];
+            digit_bytes
+                .chunks(chunk_size)
+                .all(|chunk| first_chunk == chunk)
+        })
+}
+
+/// Sums every ID in the set made of a repeated pattern, counting overlapping ranges once.
+pub fn sum_repeating_patterns(ids: &IntervalSet) -> u64 {
+    ids.iter().flatten().filter(check_repeating_pattern).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{
+        Part,
+        oracle::{
+            check_against,
+            proptest::prelude::{Strategy, prop},
+        },
+    };
+
+    use super::*;
+
+    fn solution(input: &str) -> u64 {
+        Day02::part_two(&Day02::parse(input).unwrap())
+    }
+
+    #[test]
+    fn test_invalid_id_11_22() {
+        assert_eq!(solution("11-22"), 33);
+    }
+
+    #[test]
+    fn test_invalid_id_1188511880_1188511890() {
+        assert_eq!(solution("1188511880-1188511890"), 1188511885);
+    }
+
+    #[test]
+    fn test_invalid_id_95_115() {
+        assert_eq!(solution("95-115"), 210);
+    }
+
+    #[test]
+    fn test_invalid_id_123123123_123123123() {
+        assert_eq!(solution("123123123-123123123"), 123123123);
+    }
+
+    #[test]
+    fn test_overlapping_ranges_count_ids_once() {
+        assert_eq!(solution("11-22,20-33"), 11 + 22 + 33);
+    }
+
+    #[test]
+    fn test_malformed_range_reports_position() {
+        let err = Day02::parse("11-22,95x115").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.expected, "a range like `11-22`");
+    }
+
+    /// Checks every way of splitting each ID into equal repeated chunks.
+    fn every_split(ids: &IntervalSet) -> u64 {
+        ids.iter()
+            .flatten()
+            .filter(|id| {
+                let id = id.to_string();
+                (2..=id.len()).any(|chunks| id[..id.len() / chunks].repeat(chunks) == id)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_matches_every_split() {
+        let ranges = prop::collection::vec((1u64..200_000, 0u64..300), 1..5).prop_map(|ranges| {
+            ranges
+                .iter()
+                .map(|(start, length)| format!("{start}-{}", start + length))
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+        check_against::<Day02, _>(Part::Two, ranges, every_split);
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day02::generate(&mut Rng::new(1), 20).unwrap();
+        assert_eq!(Day02::parse(&input).unwrap().iter().count(), 20);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,235 @@
+use aoc_core::{ParseError, Rng, Solution, Unsolved};
+
+/// Batteries turned on per bank.
+pub const BATTERY_SIZE: usize = 12;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+
+    type Input = Vec<Vec<char>>;
+    type PartOne = Unsolved;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| parse_bank(input, line.trim()))
+            .collect()
+    }
+
+    fn part_one(_banks: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_two(banks: &Self::Input) -> u64 {
+        banks
+            .iter()
+            .map(|joltages| battery_joltage(joltages, BATTERY_SIZE))
+            .sum()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let banks: Vec<String> = (0..size)
+            .map(|_| {
+                (0..100)
+                    .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                    .collect()
+            })
+            .collect();
+        Some(banks.join("\n"))
+    }
+}
+
+/// The batteries picked from a bank, in bank order.
+pub struct Battery {
+    cells: Vec<char>,
+    size: usize,
+}
+
+impl Battery {
+    pub fn new(size: usize) -> Self {
+        Self {
+            cells: Vec::with_capacity(size),
+            size,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.cells.len() == self.size
+    }
+
+    fn remaining_capacity(&self) -> usize {
+        self.size - self.cells.len()
+    }
+
+    pub fn total_joltage(&self) -> u64 {
+        self.cells
+            .iter()
+            .fold(0u64, |acc, &c| acc * 10 + c.to_digit(10).unwrap() as u64)
+    }
+
+    /// Greedily picks the largest joltages that still leave room to fill the battery.
+    pub fn fill_up_cells_from(&mut self, joltages: &[char]) {
+        let mut remaining_joltages = joltages;
+        while let Some((index, joltage)) = self.find_best_joltage(remaining_joltages) {
+            self.cells.push(joltage);
+            remaining_joltages = &remaining_joltages[index + 1..];
+            if self.is_full() {
+                break;
+            }
+        }
+    }
+
+    fn find_best_joltage(&self, joltages: &[char]) -> Option<(usize, char)> {
+        for digit_char in ('0'..='9').rev() {
+            if let Some(digit_index) = joltages.iter().position(|&c| c == digit_char) {
+                let remaining_jolts = joltages.len() - digit_index;
+                if remaining_jolts >= self.remaining_capacity() {
+                    return Some((digit_index, digit_char));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_bank(input: &str, line: &str) -> Result<Vec<char>, ParseError> {
+    line.char_indices()
+        .map(|(index, joltage)| match joltage {
+            '0'..='9' => Ok(joltage),
+            _ => Err(ParseError::at(
+                input,
+                &line[index..index + joltage.len_utf8()],
+                "a joltage digit",
+            )),
+        })
+        .collect()
+}
+
+/// The largest joltage a bank can produce with `battery_size` batteries on.
+pub fn battery_joltage(joltages: &[char], battery_size: usize) -> u64 {
+    let mut battery = Battery::new(battery_size);
+    battery.fill_up_cells_from(joltages);
+    battery.total_joltage()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{
+        Part,
+        oracle::{
+            check_against,
+            proptest::prelude::{Strategy, prop},
+        },
+    };
+
+    use super::*;
+
+    fn create_battery(input: &str, battery_size: usize) -> u64 {
+        let joltages: Vec<char> = input.trim().chars().collect();
+        battery_joltage(&joltages, battery_size)
+    }
+
+    #[test]
+    fn test_should_return_highest_number_with_battery_size_3() {
+        assert_eq!(create_battery("811111111111191", 3), 891);
+        assert_eq!(create_battery("234234234234278", 3), 478);
+        assert_eq!(create_battery("818181911112111", 3), 921);
+    }
+
+    #[test]
+    fn test_should_return_highest_number_with_battery_size_2() {
+        assert_eq!(create_battery("987654321111111", 2), 98);
+        assert_eq!(create_battery("811111111111119", 2), 89);
+        assert_eq!(create_battery("234234234234278", 2), 78);
+        assert_eq!(create_battery("818181911112111", 2), 92);
+    }
+
+    #[test]
+    fn test_should_return_highest_number_with_battery_size_4() {
+        let input = "3465793544554539453556366463344563446545344434374421565553674754454364545353445746344674866324626454";
+        assert_eq!(create_battery(input, 4), 9986);
+    }
+
+    #[test]
+    fn test_should_return_highest_number_with_battery_size_12() {
+        assert_eq!(
+            create_battery("987654321111111", BATTERY_SIZE),
+            987654321111
+        );
+        assert_eq!(
+            create_battery("811111111111119", BATTERY_SIZE),
+            811111111119
+        );
+        assert_eq!(
+            create_battery("234234234234278", BATTERY_SIZE),
+            434234234278
+        );
+        assert_eq!(
+            create_battery("818181911112111", BATTERY_SIZE),
+            888911112111
+        );
+    }
+
+    #[test]
+    fn test_zero_joltages_can_be_turned_on() {
+        assert_eq!(create_battery("1090", 3), 190);
+        assert_eq!(create_battery("100", 2), 10);
+    }
+
+    /// Tries every way of turning on `BATTERY_SIZE` batteries in each bank.
+    fn every_subsequence(banks: &[Vec<char>]) -> u64 {
+        banks
+            .iter()
+            .map(|bank| {
+                (0u32..1 << bank.len())
+                    .filter(|picked| picked.count_ones() as usize == BATTERY_SIZE)
+                    .map(|picked| {
+                        bank.iter()
+                            .enumerate()
+                            .filter(|(index, _)| picked & 1 << index != 0)
+                            .fold(0, |joltage, (_, c)| {
+                                joltage * 10 + u64::from(c.to_digit(10).unwrap())
+                            })
+                    })
+                    .max()
+                    .unwrap_or_default()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_matches_every_subsequence() {
+        let bank = prop::collection::vec(0u32..10, BATTERY_SIZE..=16)
+            .prop_map(|bank| bank.iter().map(u32::to_string).collect::<String>());
+        let banks = prop::collection::vec(bank, 1..4).prop_map(|banks| banks.join("\n"));
+        check_against::<Day03, _>(Part::Two, banks, |banks| every_subsequence(banks));
+    }
+
+    #[test]
+    fn test_non_digit_joltage_reports_position() {
+        let err = Day03::parse("987654321111111\n81111x111111119")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.found, "`x`");
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day03::generate(&mut Rng::new(1), 10).unwrap();
+        let banks = Day03::parse(&input).unwrap();
+        assert_eq!(banks.len(), 10);
+        assert!(banks.iter().all(|bank| bank.len() == 100));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_core::{Grid, ParseError, Rng, Solution, Unsolved, parse};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+
+    type Input = Grid<char>;
+    type PartOne = Unsolved;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::grid(input, "@.")
+    }
+
+    fn part_one(_grid: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_two(grid: &Self::Input) -> u64 {
+        let mut grid = grid.clone();
+        let mut total_removed_rolls = 0u64;
+        while let Some(removed_rolls) = remove_rolls(&mut grid) {
+            total_removed_rolls += removed_rolls;
+        }
+        total_removed_rolls
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows: Vec<String> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(0.6) { PAPER_ROLL } else { EMPTY })
+                    .collect()
+            })
+            .collect();
+        Some(rows.join("\n"))
+    }
+}
+
+const PAPER_ROLL: char = '@';
+const EMPTY: char = '.';
+
+/// Removes every roll reachable by a forklift in one pass; `None` once nothing moves.
+pub fn remove_rolls(grid: &mut Grid<char>) -> Option<u64> {
+    let mut removed_rolls = 0;
+    for point in grid.points() {
+        if grid[point] != PAPER_ROLL {
+            continue;
+        }
+        let paper_roll_neighbours_size = grid
+            .neighbors8(point)
+            .filter(|&neighbor| grid[neighbor] == PAPER_ROLL)
+            .count();
+        if paper_roll_neighbours_size < 4 {
+            removed_rolls += 1;
+            grid[point] = EMPTY;
+        }
+    }
+    (removed_rolls > 0).then_some(removed_rolls)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{
+        Part,
+        oracle::{
+            check_against,
+            proptest::prelude::{Strategy, prop},
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_ragged_row_reports_position() {
+        let err = Day04::parse("..@@.\n@@@.\n@.@@.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row of 5 cells");
+    }
+
+    /// Removes one accessible roll at a time until none is left.
+    fn one_at_a_time(grid: &Grid<char>) -> u64 {
+        let mut rolls: Vec<Vec<bool>> = grid
+            .rows()
+            .map(|row| row.iter().map(|&c| c == PAPER_ROLL).collect())
+            .collect();
+        let (width, height) = (grid.width() as isize, grid.height() as isize);
+        let accessible = |rolls: &Vec<Vec<bool>>, x: isize, y: isize| {
+            let neighbours = (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                .filter(|&(nx, ny)| (nx, ny) != (x, y))
+                .filter(|&(nx, ny)| (0..width).contains(&nx) && (0..height).contains(&ny))
+                .filter(|&(nx, ny)| rolls[ny as usize][nx as usize])
+                .count();
+            rolls[y as usize][x as usize] && neighbours < 4
+        };
+        let mut removed = 0;
+        while let Some((x, y)) = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .find(|&(x, y)| accessible(&rolls, x, y))
+        {
+            rolls[y as usize][x as usize] = false;
+            removed += 1;
+        }
+        removed
+    }
+
+    #[test]
+    fn test_matches_one_at_a_time() {
+        let grids = (1usize..8, 1usize..8).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(prop::sample::select(vec!['@', '.']), width)
+                .prop_map(String::from_iter);
+            prop::collection::vec(row, height).prop_map(|rows| rows.join("\n"))
+        });
+        check_against::<Day04, _>(Part::Two, grids, one_at_a_time);
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day04::generate(&mut Rng::new(1), 30).unwrap();
+        let grid = Day04::parse(&input).unwrap();
+        assert_eq!((grid.width(), grid.height()), (30, 30));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
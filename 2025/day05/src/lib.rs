@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use aoc_core::{IntervalSet, ParseError, Rng, Solution, parse};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+
+    type Input = Inventory;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Inventory::new(input)
+    }
+
+    fn part_one(inventory: &Self::Input) -> u64 {
+        let fresh_ingredients = inventory.find_fresh_ingredients_from_stash();
+        fresh_ingredients.len() as u64
+    }
+
+    fn part_two(inventory: &Self::Input) -> u64 {
+        inventory.fresh.size()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Both sections need at least one entry to parse.
+        let size = size.max(1);
+        let ranges: Vec<(u64, u64)> = (0..size)
+            .map(|_| {
+                let start = rng.range(1..=1_000_000_000_000_000);
+                (start, start + rng.range(0..=1_000_000_000_000))
+            })
+            .collect();
+        let ingredients: Vec<String> = (0..size * 5)
+            .map(|_| {
+                let id = if rng.chance(0.5) {
+                    let &(start, end) = rng.pick(&ranges);
+                    rng.range(start..=end)
+                } else {
+                    rng.range(1..=1_001_000_000_000_000)
+                };
+                id.to_string()
+            })
+            .collect();
+        let ranges: Vec<String> = ranges
+            .iter()
+            .map(|(start, end)| format!("{start}-{end}"))
+            .collect();
+        Some(format!(
+            "{}\n\n{}",
+            ranges.join("\n"),
+            ingredients.join("\n")
+        ))
+    }
+}
+
+/// The fresh ID ranges and the available ingredient IDs.
+#[derive(Debug)]
+pub struct Inventory {
+    pub fresh: IntervalSet,
+    pub ingredients: HashSet<u64>,
+}
+
+impl Inventory {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let [ranges, ingredients] =
+            parse::split_sections(input, "a blank line between ranges and ingredients")?;
+        let fresh = parse::non_blank_lines(ranges)
+            .map(|line| parse::range(input, line, "a range like `3-5`", "an ingredient ID"))
+            .collect::<Result<IntervalSet, ParseError>>()?;
+        let ingredients = parse::non_blank_lines(ingredients)
+            .map(|line| parse::number(input, line, "an ingredient ID"))
+            .collect::<Result<HashSet<u64>, ParseError>>()?;
+        Ok(Self { fresh, ingredients })
+    }
+
+    /// Available ingredients that fall inside any fresh range.
+    pub fn find_fresh_ingredients_from_stash(&self) -> Vec<u64> {
+        self.ingredients
+            .iter()
+            .filter(|&&ingredient| self.fresh.contains(ingredient))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::oracle::{
+        check_inputs,
+        proptest::prelude::{TestCaseError, prop, prop_assert_eq},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_missing_blank_line_is_reported() {
+        let err = Day05::parse("3-5\n10-14\n1\n5").err().unwrap();
+        assert_eq!(err.expected, "a blank line between ranges and ingredients");
+        assert_eq!(err.line, 4);
+    }
+
+    #[test]
+    fn test_bad_ingredient_reports_position() {
+        let err = Day05::parse("3-5\n10-14\n\n1\n5a").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.found, "`5a`");
+    }
+
+    /// Checks every ingredient against every range, and lists every fresh ID.
+    #[test]
+    fn test_matches_checking_every_id() {
+        let ranges = prop::collection::vec((0u64..50, 0u64..10), 1..6);
+        let ingredients = prop::collection::btree_set(0u64..70, 1..10);
+        check_inputs((ranges, ingredients), |(ranges, ingredients)| {
+            let ranges: Vec<(u64, u64)> = ranges
+                .iter()
+                .map(|&(start, length)| (start, start + length))
+                .collect();
+            let input = format!(
+                "{}\n\n{}",
+                ranges
+                    .iter()
+                    .map(|(start, end)| format!("{start}-{end}"))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                ingredients
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+            let inventory =
+                Day05::parse(&input).map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+            let fresh = ingredients
+                .iter()
+                .filter(|&&id| {
+                    ranges
+                        .iter()
+                        .any(|&(start, end)| (start..=end).contains(&id))
+                })
+                .count() as u64;
+            prop_assert_eq!(Day05::part_one(&inventory), fresh);
+            let ids: HashSet<u64> = ranges
+                .iter()
+                .flat_map(|&(start, end)| start..=end)
+                .collect();
+            prop_assert_eq!(Day05::part_two(&inventory), ids.len() as u64);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day05::generate(&mut Rng::new(1), 20).unwrap();
+        assert_eq!(Day05::parse(&input).unwrap().ingredients.len(), 100);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
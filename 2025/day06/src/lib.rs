@@ -0,0 +1,393 @@
+use aoc_core::{ParseError, Rng, Solution, parse};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+
+    type Input = Problems;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Problems {
+            by_rows: parse_problems_by_rows(input)?,
+            by_columns: parse_problems_by_columns(input)?,
+        })
+    }
+
+    fn part_one(problems: &Self::Input) -> u64 {
+        problems.by_rows.iter().map(|p| p.solve()).sum()
+    }
+
+    fn part_two(problems: &Self::Input) -> u64 {
+        problems.by_columns.iter().map(|p| p.solve()).sum()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_worksheet(rng, size))
+    }
+}
+
+/// The worksheet read row by row (part one) and column by column (part two).
+pub struct Problems {
+    pub by_rows: Vec<Problem>,
+    pub by_columns: Vec<Problem>,
+}
+
+pub type Operation = fn(u64, u64) -> u64;
+
+/// A column of numbers combined with a single operation.
+pub struct Problem {
+    pub numbers: Vec<u64>,
+    pub operation: Operation,
+}
+
+impl Problem {
+    pub const ADD: Operation = |a: u64, b: u64| a + b;
+    pub const MULTIPLY: Operation = |a: u64, b: u64| a * b;
+
+    /// The numbers combined with the operation; parsing never yields a problem
+    /// without numbers, which would count as `0`.
+    pub fn solve(&self) -> u64 {
+        self.numbers
+            .iter()
+            .copied()
+            .reduce(self.operation)
+            .unwrap_or_default()
+    }
+
+    fn map_problem<'a>(
+        input: &str,
+        worksheet: &'a Worksheet<'a>,
+    ) -> Result<Vec<(Vec<&'a str>, Operation)>, ParseError> {
+        worksheet
+            .pivot_grid()
+            .iter()
+            .map(|problem| {
+                let (operation, numbers) = match problem.split_last() {
+                    Some((operation, numbers)) if !numbers.is_empty() => (operation, numbers),
+                    _ => {
+                        return Err(ParseError::at_end(
+                            input,
+                            "rows of numbers above the operations",
+                        ));
+                    }
+                };
+                let operation = match operation.trim() {
+                    "*" => Self::MULTIPLY,
+                    "+" => Self::ADD,
+                    _ => return Err(ParseError::at(input, operation.trim(), "`+` or `*`")),
+                };
+                Ok((numbers.to_vec(), operation))
+            })
+            .collect()
+    }
+
+    fn map_problem_1(input: &str, worksheet: &Worksheet) -> Result<Vec<Problem>, ParseError> {
+        let problems = Problem::map_problem(input, worksheet)?;
+        problems
+            .iter()
+            .map(|(numbers, operation)| {
+                Ok(Problem {
+                    numbers: numbers
+                        .iter()
+                        .map(|s| parse::number(input, s, "a number"))
+                        .collect::<Result<Vec<u64>, ParseError>>()?,
+                    operation: *operation,
+                })
+            })
+            .collect()
+    }
+
+    fn map_problem_2(input: &str, worksheet: &Worksheet) -> Result<Vec<Problem>, ParseError> {
+        let problems = Problem::map_problem(input, worksheet)?;
+        problems
+            .iter()
+            .map(|p| {
+                let (numbers, operation) = p;
+                for cell in numbers {
+                    let number = cell.trim();
+                    if number.is_empty() || number.contains(char::is_whitespace) {
+                        let span = if number.is_empty() { cell } else { number };
+                        return Err(ParseError::at(
+                            input,
+                            span,
+                            "one number per cell, aligned with its operation",
+                        ));
+                    }
+                }
+                let max_len = numbers
+                    .iter()
+                    .map(|n| n.chars().count())
+                    .max()
+                    .unwrap_or_default();
+                let column_numbers = (0..max_len)
+                    .rev()
+                    .map(|length| read_column(input, numbers, length))
+                    .collect::<Result<Vec<u64>, ParseError>>()?;
+                Ok(Problem {
+                    numbers: column_numbers,
+                    operation: *operation,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reads the digits at char position `length` of every number top to bottom;
+/// numbers too short to reach that column count as blank, but a column with no
+/// digit at all is rejected.
+fn read_column(input: &str, numbers: &[&str], length: usize) -> Result<u64, ParseError> {
+    let mut cells = numbers
+        .iter()
+        .filter_map(|s| s.char_indices().nth(length).map(|(index, c)| (s, index, c)))
+        .peekable();
+    let first = cells
+        .peek()
+        .map(|&(s, index, c)| &s[index..index + c.len_utf8()]);
+    cells
+        .filter(|(_, _, c)| !c.is_whitespace())
+        .try_fold(None, |acc: Option<u64>, (s, index, c)| {
+            c.to_digit(10)
+                .and_then(|d| acc.unwrap_or(0).checked_mul(10)?.checked_add(d as u64))
+                .map(Some)
+                .ok_or_else(|| ParseError::at(input, &s[index..index + c.len_utf8()], "a digit"))
+        })?
+        .ok_or_else(|| {
+            ParseError::at(
+                input,
+                first.unwrap_or(numbers[0]),
+                "a digit in every column of a problem",
+            )
+        })
+}
+
+/// The worksheet split into cells, one row per non-blank line.
+pub struct Worksheet<'a> {
+    grid: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Worksheet<'a> {
+    /// Cells separated by runs of whitespace.
+    pub fn by_whitespace(input: &'a str) -> Result<Self, ParseError> {
+        let grid = parse::whitespace_table(input)?;
+        Ok(Self { grid })
+    }
+
+    /// Cells starting at each of `column_starts`, padding included.
+    pub fn by_columns(input: &'a str, column_starts: &[usize]) -> Result<Self, ParseError> {
+        let grid = parse::fixed_width_table(input, column_starts)?;
+        Ok(Self { grid })
+    }
+
+    /// The cells regrouped by problem: numbers top to bottom, then the operation.
+    pub fn pivot_grid(&self) -> Vec<Vec<&str>> {
+        if self.grid.is_empty() {
+            return Vec::new();
+        }
+        (0..self.grid[0].len())
+            .map(|x| self.grid.iter().map(|row| row[x]).collect())
+            .collect()
+    }
+}
+
+/// Reads each problem's numbers left to right along the rows.
+pub fn parse_problems_by_rows(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let worksheet = Worksheet::by_whitespace(input)?;
+    Problem::map_problem_1(input, &worksheet)
+}
+
+/// Reads each problem's numbers top to bottom, one column per number, right to left.
+/// Each problem's block starts at its operation's column.
+pub fn parse_problems_by_columns(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let operation_indices = parse::non_blank_lines(input)
+        .last()
+        .ok_or_else(|| ParseError::at_end(input, "a row of operations"))?
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let worksheet = Worksheet::by_columns(input, &operation_indices)?;
+    Problem::map_problem_2(input, &worksheet)
+}
+
+/// `size` problems of four numbers with up to four digits each, every number
+/// padded on a random side to its problem's width, like the real worksheet.
+/// There is always at least one problem, since a worksheet needs its row of
+/// operations.
+fn generate_worksheet(rng: &mut Rng, size: usize) -> String {
+    let mut rows = vec![String::new(); 5];
+    for problem in 0..size.max(1) {
+        let numbers: Vec<String> = (0..4)
+            .map(|_| {
+                let digits = rng.range(1..=4) as u32;
+                rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+                    .to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or_default();
+        if problem > 0 {
+            rows.iter_mut().for_each(|row| row.push(' '));
+        }
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            if rng.chance(0.5) {
+                row.push_str(&format!("{number:<width$}"));
+            } else {
+                row.push_str(&format!("{number:>width$}"));
+            }
+        }
+        rows[4].push_str(&format!("{:<width$}", rng.pick(&['+', '*'])));
+    }
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::oracle::{
+        check_inputs,
+        proptest::prelude::{Strategy, TestCaseError, any, prop, prop_assert_eq},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_unknown_operation_reports_position() {
+        let err = Day06::parse("12 3\n4  5\n*  -").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 4));
+        assert_eq!(err.expected, "`+` or `*`");
+    }
+
+    #[test]
+    fn test_ragged_row_is_reported() {
+        let err = Day06::parse("12 3 4\n4  5\n*  +").err().unwrap();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.expected, "a row of 3 entries");
+    }
+
+    #[test]
+    fn test_blank_or_misaligned_cells_are_reported() {
+        let err = Day06::parse("1 2\n+   *\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(
+            err.expected,
+            "one number per cell, aligned with its operation"
+        );
+
+        let err = Day06::parse("1  3\n2  5\n+  *").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 2));
+        assert_eq!(err.expected, "a digit in every column of a problem");
+    }
+
+    /// A problem's operation and its numbers, each padded on the left or right.
+    type Worked = (char, Vec<(u64, bool)>);
+
+    /// Lays `problems` out as a worksheet: the padded numbers as rows, then
+    /// the operations, with a blank column between problems.
+    fn worksheet(problems: &[Worked]) -> (String, Vec<Vec<String>>) {
+        let padded: Vec<Vec<String>> = problems
+            .iter()
+            .map(|(_, numbers)| {
+                let width = numbers
+                    .iter()
+                    .map(|(number, _)| number.to_string().len())
+                    .max()
+                    .unwrap_or_default();
+                numbers
+                    .iter()
+                    .map(|&(number, left)| {
+                        if left {
+                            format!("{number:<width$}")
+                        } else {
+                            format!("{number:>width$}")
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut rows: Vec<String> = (0..problems[0].1.len())
+            .map(|row| {
+                padded
+                    .iter()
+                    .map(|cells| cells[row].as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        rows.push(
+            problems
+                .iter()
+                .zip(&padded)
+                .map(|((operation, _), cells)| {
+                    format!("{operation:<width$}", width = cells[0].len())
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        (rows.join("\n"), padded)
+    }
+
+    fn apply(operation: char, numbers: impl Iterator<Item = u64>) -> u64 {
+        match operation {
+            '+' => numbers.sum(),
+            _ => numbers.product(),
+        }
+    }
+
+    /// Works each problem out from its numbers, and from the digits of each
+    /// padded column read top to bottom.
+    #[test]
+    fn test_matches_reading_the_worksheet_by_hand() {
+        let problems = (1usize..4).prop_flat_map(|rows| {
+            let problem = (
+                prop::sample::select(vec!['+', '*']),
+                prop::collection::vec((1u64..1000, any::<bool>()), rows),
+            );
+            prop::collection::vec(problem, 1..5)
+        });
+        check_inputs(problems, |problems| {
+            let (input, padded) = worksheet(problems);
+            let parsed =
+                Day06::parse(&input).map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+            let by_rows: u64 = problems
+                .iter()
+                .map(|(operation, numbers)| apply(*operation, numbers.iter().map(|&(n, _)| n)))
+                .sum();
+            prop_assert_eq!(Day06::part_one(&parsed), by_rows);
+            let by_columns: u64 = problems
+                .iter()
+                .zip(&padded)
+                .map(|((operation, _), cells)| {
+                    let columns = (0..cells[0].len()).map(|column| {
+                        cells
+                            .iter()
+                            .map(|cell| cell.as_bytes()[column])
+                            .filter(u8::is_ascii_digit)
+                            .fold(0, |number, digit| number * 10 + u64::from(digit - b'0'))
+                    });
+                    apply(*operation, columns)
+                })
+                .sum();
+            prop_assert_eq!(Day06::part_two(&parsed), by_columns);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day06::generate(&mut Rng::new(1), 25).unwrap();
+        let problems = Day06::parse(&input).unwrap();
+        assert_eq!(
+            (problems.by_rows.len(), problems.by_columns.len()),
+            (25, 25)
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
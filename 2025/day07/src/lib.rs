@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use aoc_core::{Graph, Grid, ParseError, Point, Rng, Solution, parse};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+
+    type Input = Manifold;
+    type PartOne = u64;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (grid, start) = parse_grid(input)?;
+        let manifold = Manifold::trace(&grid, start);
+        if manifold.count_timelines().is_none() {
+            return Err(ParseError::at_end(
+                input,
+                "a manifold whose timelines fit in a u128",
+            ));
+        }
+        Ok(manifold)
+    }
+
+    fn part_one(manifold: &Self::Input) -> u64 {
+        manifold.count_split_occurrence()
+    }
+
+    fn part_two(manifold: &Self::Input) -> u128 {
+        manifold
+            .count_timelines()
+            .expect("Parsing rejects manifolds with too many timelines!")
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_manifold(rng, size))
+    }
+}
+
+pub const BEAM_ENTRANCE: char = 'S';
+
+/// The beams traced through the manifold: an edge from each beam position to
+/// the positions it continues to on the next row.
+#[derive(Debug)]
+pub struct Manifold {
+    pub beams: Graph<Point>,
+    /// Beam positions directly above a splitter.
+    pub splits: HashSet<Point>,
+    pub start: Point,
+}
+
+impl Manifold {
+    /// Traces the beams down from `start`, splitting at every `^`.
+    pub fn trace(grid: &Grid<char>, start: Point) -> Self {
+        let mut beams = Graph::new();
+        let mut splits = HashSet::new();
+        beams.add_node(start);
+
+        let mut current_positions = vec![start];
+
+        for y in start.y + 1..grid.height() {
+            let mut next_beams = Vec::new();
+            for &Point { x, .. } in &current_positions {
+                let node_in_scope = Point::new(x, y - 1);
+                match grid[Point::new(x, y)] {
+                    '^' => {
+                        splits.insert(node_in_scope);
+                        if x > 0 {
+                            let split_left = Point::new(x - 1, y);
+                            beams.add_edge(node_in_scope, split_left);
+                            next_beams.push(split_left);
+                        }
+                        if x + 1 < grid.width() {
+                            let split_right = Point::new(x + 1, y);
+                            beams.add_edge(node_in_scope, split_right);
+                            next_beams.push(split_right);
+                        }
+                    }
+                    _ => {
+                        let position = Point::new(x, y);
+                        beams.add_edge(node_in_scope, position);
+                        next_beams.push(position);
+                    }
+                }
+            }
+            next_beams.sort();
+            next_beams.dedup();
+            current_positions = next_beams;
+        }
+        Self {
+            beams,
+            splits,
+            start,
+        }
+    }
+
+    /// How many splitters the beams hit.
+    pub fn count_split_occurrence(&self) -> u64 {
+        self.beams
+            .reachable(&self.start)
+            .into_iter()
+            .filter(|position| self.splits.contains(position))
+            .count() as u64
+    }
+
+    /// How many distinct paths a single particle can take from the entrance,
+    /// or `None` if there are more than fit in a `u128`. Beams only travel
+    /// downwards, so the beam graph never has a cycle.
+    pub fn count_timelines(&self) -> Option<u128> {
+        self.beams.count_paths_where(&self.start, |position| {
+            self.beams.successors(position).next().is_none()
+        })
+    }
+}
+
+fn parse_grid(input: &str) -> Result<(Grid<char>, Point), ParseError> {
+    let grid = parse::grid(input, ".^S")?;
+    let start = grid
+        .position(&BEAM_ENTRANCE)
+        .ok_or_else(|| ParseError::at_end(input, "a beam entrance `S`"))?;
+    Ok((grid, start))
+}
+
+/// A `size` by `size` manifold (rounded up to an odd width) with the entrance
+/// centred on the top row and splitters on every other row. Splitters get
+/// sparser as the manifold grows so the timeline count still fits in a `u64`.
+fn generate_manifold(rng: &mut Rng, size: usize) -> String {
+    let height = size.max(2);
+    let width = height | 1;
+    let density = (40.0 / height as f64).min(0.5);
+    let mut rows = Vec::with_capacity(height);
+    for y in 0..height {
+        let mut row = String::with_capacity(width);
+        for x in 0..width {
+            let cell = if y == 0 && x == width / 2 {
+                BEAM_ENTRANCE
+            } else if y > 0 && y % 2 == 0 && !row.ends_with('^') && rng.chance(density) {
+                '^'
+            } else {
+                '.'
+            };
+            row.push(cell);
+        }
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::oracle::{
+        check_inputs,
+        proptest::prelude::{Strategy, TestCaseError, prop, prop_assert_eq},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_unknown_cell_reports_position() {
+        let err = Day07::parse("..S..\n..#..").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "`.`, `^` or `S`");
+    }
+
+    #[test]
+    fn test_missing_entrance_is_reported() {
+        let err = Day07::parse(".....\n..^..").err().unwrap();
+        assert_eq!(err.expected, "a beam entrance `S`");
+    }
+
+    /// Moves the beams down one row at a time, noting every splitter they hit.
+    fn splitters_hit(rows: &[Vec<char>], entrance: usize) -> u64 {
+        let width = rows[0].len();
+        let mut beams = HashSet::from([entrance]);
+        let mut hit = HashSet::new();
+        for (y, row) in rows.iter().enumerate().skip(1) {
+            let mut next = HashSet::new();
+            for &x in &beams {
+                if row[x] == '^' {
+                    hit.insert((x, y));
+                    next.extend(
+                        [x.checked_sub(1), Some(x + 1).filter(|&x| x < width)]
+                            .into_iter()
+                            .flatten(),
+                    );
+                } else {
+                    next.insert(x);
+                }
+            }
+            beams = next;
+        }
+        hit.len() as u64
+    }
+
+    /// Follows one particle down every way it can go. A particle with nowhere
+    /// to go still ends a timeline.
+    fn timelines_from(rows: &[Vec<char>], x: usize, y: usize) -> u128 {
+        let Some(row) = rows.get(y + 1) else {
+            return 1;
+        };
+        if row[x] != '^' {
+            return timelines_from(rows, x, y + 1);
+        }
+        let sides: Vec<usize> = [x.checked_sub(1), Some(x + 1).filter(|&x| x < row.len())]
+            .into_iter()
+            .flatten()
+            .collect();
+        if sides.is_empty() {
+            return 1;
+        }
+        sides.iter().map(|&x| timelines_from(rows, x, y + 1)).sum()
+    }
+
+    #[test]
+    fn test_matches_following_each_particle() {
+        let manifolds = (1usize..8, 1usize..8).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(prop::sample::select(vec!['.', '.', '^']), width);
+            (0..width, prop::collection::vec(row, height))
+        });
+        check_inputs(manifolds, |(entrance, rows)| {
+            let mut rows = rows.clone();
+            rows.insert(0, vec!['.'; rows[0].len()]);
+            rows[0][*entrance] = BEAM_ENTRANCE;
+            let input = rows
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let manifold =
+                Day07::parse(&input).map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+            prop_assert_eq!(Day07::part_one(&manifold), splitters_hit(&rows, *entrance));
+            prop_assert_eq!(
+                Day07::part_two(&manifold),
+                timelines_from(&rows, *entrance, 0)
+            );
+            Ok(())
+        });
+    }
+
+    /// An entrance above `layers` rows of splitters, each row wide enough
+    /// that every beam hits one, so each row doubles the timelines.
+    fn splitter_pyramid(layers: usize) -> String {
+        let width = 2 * layers + 5;
+        let centre = width / 2;
+        let mut rows = vec![format!("{}S{}", ".".repeat(centre), ".".repeat(centre))];
+        for layer in 0..layers {
+            let mut row = vec!['.'; width];
+            for offset in (0..=2 * layer).step_by(2) {
+                row[centre + offset - layer] = '^';
+            }
+            rows.push(".".repeat(width));
+            rows.push(row.into_iter().collect());
+        }
+        rows.join("\n")
+    }
+
+    #[test]
+    fn test_timelines_beyond_a_u64_are_counted() {
+        let manifold = Day07::parse(&splitter_pyramid(70)).unwrap();
+        assert_eq!(Day07::part_two(&manifold), 1 << 70);
+    }
+
+    #[test]
+    fn test_timelines_beyond_a_u128_are_rejected() {
+        let err = Day07::parse(&splitter_pyramid(130)).err().unwrap();
+        assert_eq!(err.expected, "a manifold whose timelines fit in a u128");
+    }
+
+    #[test]
+    fn test_generated_input_is_valid() {
+        let input = Day07::generate(&mut Rng::new(1), 40).unwrap();
+        let manifold = Day07::parse(&input).unwrap();
+        assert!(manifold.count_split_occurrence() > 0);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
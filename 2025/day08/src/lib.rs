@@ -0,0 +1,37 @@
+use aoc_core::{ParseError, Solution, Unsolved};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+
+    type Input = String;
+    type PartOne = Unsolved;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(_input: &Self::Input) -> Unsolved {
+        // TODO: Implement solution
+        Unsolved
+    }
+
+    fn part_two(_input: &Self::Input) -> Unsolved {
+        // TODO: Implement solution
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,27 +1,7 @@
-use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let input = fs::read_to_string("day09/input.txt").expect("Failed to read input file");
+use day09::Day09;
 
-    println!("Day 9: Advent of Code 2025");
-    println!("=========================");
-
-    let result = solution(&input);
-    println!("Result: {}", result);
-}
-
-fn solution(input: &str) -> i64 {
-    // TODO: Implement solution
-    0
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_sample_input() {
-        let input = ""; // Add test input
-        assert_eq!(solution(input), 0);
-    }
+fn main() -> ExitCode {
+    aoc_core::cli::main::<Day09>(env!("CARGO_MANIFEST_DIR"))
 }
@@ -0,0 +1,37 @@
+use aoc_core::{ParseError, Solution, Unsolved};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Christmas Tree Farm";
+
+    type Input = String;
+    type PartOne = Unsolved;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(_input: &Self::Input) -> Unsolved {
+        // TODO: Implement solution
+        Unsolved
+    }
+
+    fn part_two(_input: &Self::Input) -> Unsolved {
+        // TODO: Implement solution
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_core::check_examples::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
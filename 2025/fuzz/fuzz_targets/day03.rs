@@ -0,0 +1,6 @@
+#![no_main]
+
+use day03::Day03;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day03>(input));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use day04::Day04;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day04>(input));
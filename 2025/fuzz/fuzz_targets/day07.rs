@@ -0,0 +1,6 @@
+#![no_main]
+
+use day07::Day07;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day07>(input));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use day08::Day08;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day08>(input));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use day11::Day11;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day11>(input));
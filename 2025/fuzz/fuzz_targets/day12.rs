@@ -0,0 +1,6 @@
+#![no_main]
+
+use day12::Day12;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse::<Day12>(input));
@@ -0,0 +1,16 @@
+use aoc_core::Solution;
+
+/// Parses `input` with `S`, which must return a model or a [`ParseError`]
+/// pointing inside the input; panicking is the bug being looked for.
+///
+/// [`ParseError`]: aoc_core::ParseError
+pub fn parse<S: Solution>(input: &str) {
+    if let Err(err) = S::parse(input) {
+        let lines = input.split('\n').count();
+        assert!(
+            (1..=lines).contains(&err.line) && err.column >= 1,
+            "error points outside the input: {err:?}"
+        );
+        let _ = err.to_string();
+    }
+}